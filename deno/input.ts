@@ -1,6 +1,15 @@
+let inputPath: string | undefined;
+
+// Reads the primary input from `path` instead of inputs/, for running on other inputs.
+export function setInputPath(path: string) {
+    inputPath = path;
+}
+
 export async function readInput(year: number, day: number, filename: string = "input"): Promise<string> {
+    if (filename === "input" && inputPath !== undefined) {
+        return await Deno.readTextFile(inputPath);
+    }
     const dayStr = day.toString().padStart(2, '0');
     const path = new URL(`../inputs/${year}/day_${dayStr}/${filename}.txt`, import.meta.url);
     return await Deno.readTextFile(path);
 }
-  
\ No newline at end of file
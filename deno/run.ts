@@ -1,11 +1,17 @@
-if (Deno.args.length !== 2) {
-    console.error("Usage: deno run run.ts <input> <output>");
+import { setInputPath } from "./input.ts";
+
+if (Deno.args.length !== 2 && Deno.args.length !== 3) {
+    console.error("Usage: deno run --allow-read run.ts <year> <day> [input]");
     Deno.exit(1);
 }
 
-const [year, day] = Deno.args;
+const [year, day, input] = Deno.args;
 const dayPadded = day.padStart(2, "0");
 
+if (input !== undefined) {
+    setInputPath(input);
+}
+
 try {
     await import(`./${year}/day_${dayPadded}/main.ts`);
 } catch (error) {
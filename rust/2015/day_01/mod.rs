@@ -0,0 +1,239 @@
+use crate::{input::PuzzleInput, random::Rng, Error, Solution};
+use serde_json::{json, Value};
+use std::io::{ErrorKind, Read};
+
+/// How much of the instructions is read at a time.
+const CHUNK_SIZE: usize = 64 * 1024;
+
+/// Everything one pass over the instructions tells us.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FloorReport {
+    pub final_floor: i64,
+    pub highest_floor: i64,
+    pub lowest_floor: i64,
+    /// 1-based positions of every instruction that took Santa from the ground
+    /// floor into the basement.
+    pub basement_entries: Vec<u64>,
+}
+
+/// Follows the instructions from `reader` a chunk at a time, so a file or
+/// stdin passed straight in never has to fit in memory. [`Day01`] follows the
+/// input the runner has already read. Line breaks are skipped and don't count
+/// towards positions; anything else but `(` and `)` is an error at its line
+/// and column.
+pub fn track_floors(mut reader: impl Read) -> Result<FloorReport, Error> {
+    let mut report = FloorReport {
+        final_floor: 0,
+        highest_floor: 0,
+        lowest_floor: 0,
+        basement_entries: Vec::new(),
+    };
+    let (mut position, mut line, mut column) = (0u64, 1, 0);
+    let mut chunk = vec![0; CHUNK_SIZE];
+
+    loop {
+        let length = match reader.read(&mut chunk) {
+            Ok(0) => return Ok(report),
+            Ok(length) => length,
+            Err(e) if e.kind() == ErrorKind::Interrupted => continue,
+            Err(e) => return Err(Error::new(format!("Could not read the instructions: {e}"))),
+        };
+
+        for &byte in &chunk[..length] {
+            // Columns count characters, so the rest of a UTF-8 sequence doesn't move them
+            if byte & 0xC0 != 0x80 {
+                column += 1;
+            }
+            let step = match byte {
+                b'(' => 1,
+                b')' => -1,
+                b'\n' => {
+                    (line, column) = (line + 1, 0);
+                    continue;
+                }
+                b'\r' => continue,
+                _ => {
+                    let found = if byte.is_ascii() {
+                        format!("{:?}", char::from(byte))
+                    } else {
+                        "a non-ASCII character".to_string()
+                    };
+                    return Err(Error::at_position(
+                        line,
+                        column,
+                        format!("Expected ( or ), found {found}"),
+                    ));
+                }
+            };
+
+            position += 1;
+            report.final_floor += step;
+            report.highest_floor = report.highest_floor.max(report.final_floor);
+            report.lowest_floor = report.lowest_floor.min(report.final_floor);
+            if report.final_floor == -1 && step == -1 {
+                report.basement_entries.push(position);
+            }
+        }
+    }
+}
+
+/// Part 1's answer, displayed as the final floor.
+pub struct FinalFloor {
+    pub floor: i64,
+    pub highest: i64,
+    pub lowest: i64,
+}
+
+impl std::fmt::Display for FinalFloor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.floor)
+    }
+}
+
+/// Part 2's answer, displayed as the position of the first step into the
+/// basement, or `never` if Santa stays above it.
+pub struct BasementEntries {
+    pub positions: Vec<u64>,
+}
+
+impl std::fmt::Display for BasementEntries {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.positions.first() {
+            Some(position) => write!(f, "{position}"),
+            None => write!(f, "never"),
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct Day01;
+
+impl Solution for Day01 {
+    type Input<'a> = FloorReport;
+    type Output1 = FinalFloor;
+    type Output2 = BasementEntries;
+
+    const PUZZLE_SIZE: usize = 7_000;
+
+    fn parse<'a>(&self, input: &'a PuzzleInput) -> Result<Self::Input<'a>, Error> {
+        track_floors(input.as_bytes())
+    }
+
+    fn part_1(&self, report: &FloorReport) -> FinalFloor {
+        FinalFloor {
+            floor: report.final_floor,
+            highest: report.highest_floor,
+            lowest: report.lowest_floor,
+        }
+    }
+
+    fn part_2(&self, report: &FloorReport) -> BasementEntries {
+        BasementEntries {
+            positions: report.basement_entries.clone(),
+        }
+    }
+
+    fn details_1(&self, floor: &FinalFloor) -> Option<Value> {
+        Some(json!({ "highest": floor.highest, "lowest": floor.lowest }))
+    }
+
+    fn details_2(&self, entries: &BasementEntries) -> Option<Value> {
+        Some(json!({ "all": entries.positions }))
+    }
+
+    /// `size` random steps, followed by enough `)` to reach the basement if
+    /// they never did.
+    fn generate(&self, rng: &mut Rng, size: usize) -> PuzzleInput {
+        let mut floor = 0i64;
+        let mut lowest = 0;
+        let mut instructions: String = (0..size)
+            .map(|_| {
+                let up = rng.chance(1, 2);
+                floor += if up { 1 } else { -1 };
+                lowest = lowest.min(floor);
+                if up {
+                    '('
+                } else {
+                    ')'
+                }
+            })
+            .collect();
+        if lowest >= 0 {
+            instructions.extend((0..=floor).map(|_| ')'));
+        }
+        PuzzleInput::new(instructions)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        testing::{answers, part_1, part_2},
+        DynSolution, Part,
+    };
+
+    #[test]
+    fn final_floor() {
+        for (input, floor) in [
+            ("(())", 0),
+            ("()()", 0),
+            ("(((", 3),
+            ("(()(()(", 3),
+            ("))(((((", 3),
+            ("())", -1),
+            ("))(", -1),
+            (")))", -3),
+            (")())())", -3),
+        ] {
+            assert_eq!(part_1(&Day01, input).floor, floor, "{input}");
+        }
+    }
+
+    #[test]
+    fn first_basement_position() {
+        assert_eq!(part_2(&Day01, ")").to_string(), "1");
+        assert_eq!(part_2(&Day01, "()())").to_string(), "5");
+        assert_eq!(part_2(&Day01, "(((").to_string(), "never");
+    }
+
+    #[test]
+    fn floor_statistics() {
+        let report = track_floors("())((()))))(".as_bytes()).unwrap();
+        assert_eq!(report.final_floor, -2);
+        assert_eq!((report.highest_floor, report.lowest_floor), (2, -3));
+        assert_eq!(report.basement_entries, [3, 9]);
+    }
+
+    #[test]
+    fn reads_in_chunks() {
+        let steps = "(".repeat(CHUNK_SIZE) + &")".repeat(CHUNK_SIZE + 1) + "\n";
+        let report = track_floors(steps.as_bytes()).unwrap();
+        assert_eq!(report.final_floor, -1);
+        assert_eq!(report.basement_entries, [2 * CHUNK_SIZE as u64 + 1]);
+    }
+
+    #[test]
+    fn rejects_other_characters() {
+        let error = Day01
+            .solve(&PuzzleInput::new("(()\n()x("), &[Part::One])
+            .unwrap_err();
+        assert_eq!(error.message(), "Expected ( or ), found 'x'");
+        let location = error.location().unwrap();
+        assert_eq!((location.line, location.column), (2, 3));
+        assert_eq!(location.source_line, "()x(");
+    }
+
+    #[test]
+    fn generated_instructions_reach_the_basement() {
+        for seed in 0..10 {
+            let input = Day01.generate(&mut Rng::new(seed), 50);
+            assert!(part_2(&Day01, &input).positions[0] <= input.len() as u64);
+        }
+    }
+
+    #[test]
+    fn answers_match() {
+        assert_eq!(answers(&Day01, 1), ["138", "1771"]);
+    }
+}
@@ -0,0 +1,307 @@
+use crate::{input::PuzzleInput, parse::parse_number, random::Rng, Error, Solution};
+use serde_json::{json, Value};
+use std::collections::HashMap;
+
+pub struct ChristmasBox {
+    l: u64,
+    w: u64,
+    h: u64,
+}
+
+impl ChristmasBox {
+    fn from_str(line: &str) -> Result<Self, Error> {
+        let mut dimensions = line.split('x').map(|d| parse_number(d, "dimension"));
+        match (
+            dimensions.next(),
+            dimensions.next(),
+            dimensions.next(),
+            dimensions.next(),
+        ) {
+            (Some(l), Some(w), Some(h), None) => Ok(ChristmasBox {
+                l: l?,
+                w: w?,
+                h: h?,
+            }),
+            _ => Err(Error::at(line, "Expected a box as LxWxH")),
+        }
+    }
+
+    // Everything below is `None` if it doesn't fit in a u64
+
+    fn surface_areas(&self) -> Option<[u64; 3]> {
+        Some([
+            self.l.checked_mul(self.w)?,
+            self.w.checked_mul(self.h)?,
+            self.h.checked_mul(self.l)?,
+        ])
+    }
+
+    fn wrapping_paper(&self) -> Option<u64> {
+        let areas = self.surface_areas()?;
+        let smallest_side = *areas.iter().min()?;
+        areas
+            .iter()
+            .try_fold(0u64, |sum, &area| sum.checked_add(area))?
+            .checked_mul(2)?
+            .checked_add(smallest_side)
+    }
+
+    fn ribbon(&self) -> Option<u64> {
+        let mut lengths = [self.l, self.w, self.h];
+        lengths.sort();
+        lengths[0]
+            .checked_add(lengths[1])?
+            .checked_mul(2)?
+            .checked_add(self.volume()?)
+    }
+
+    fn volume(&self) -> Option<u64> {
+        self.l.checked_mul(self.w)?.checked_mul(self.h)
+    }
+}
+
+/// One box of the order and what it takes to wrap it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OrderLine {
+    /// 1-based line of the input the box is on.
+    pub line: usize,
+    /// Length, width and height, as listed.
+    pub dimensions: [u64; 3],
+    pub volume: u64,
+    pub paper: u64,
+    pub ribbon: u64,
+}
+
+impl OrderLine {
+    fn from_str(line: usize, text: &str) -> Result<Self, Error> {
+        let christmas_box = ChristmasBox::from_str(text)?;
+        let too_large = || Error::at(text, "Box is too large to wrap");
+        Ok(OrderLine {
+            line,
+            dimensions: [christmas_box.l, christmas_box.w, christmas_box.h],
+            volume: christmas_box.volume().ok_or_else(too_large)?,
+            paper: christmas_box.wrapping_paper().ok_or_else(too_large)?,
+            ribbon: christmas_box.ribbon().ok_or_else(too_large)?,
+        })
+    }
+}
+
+/// The whole order, box by box. Totals are u128, so they can't overflow
+/// however many boxes there are.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OrderReport {
+    pub boxes: Vec<OrderLine>,
+    pub paper: u128,
+    pub ribbon: u128,
+}
+
+impl OrderReport {
+    /// Reads one box per line, reporting every invalid line rather than just the
+    /// first. A single invalid line keeps its own error, with its position.
+    pub fn parse(input: &str) -> Result<Self, Error> {
+        let mut boxes = Vec::new();
+        let mut invalid = Vec::new();
+        for (i, text) in input.lines().enumerate() {
+            match OrderLine::from_str(i + 1, text) {
+                Ok(order_line) => boxes.push(order_line),
+                Err(e) => invalid.push((i + 1, e)),
+            }
+        }
+
+        match invalid.len() {
+            0 => Ok(OrderReport {
+                paper: boxes.iter().map(|b| u128::from(b.paper)).sum(),
+                ribbon: boxes.iter().map(|b| u128::from(b.ribbon)).sum(),
+                boxes,
+            }),
+            1 => Err(invalid.remove(0).1),
+            count => {
+                let lines: Vec<String> = invalid
+                    .iter()
+                    .map(|(line, e)| format!("  line {line}: {e}"))
+                    .collect();
+                Err(Error::new(format!(
+                    "{count} invalid lines:\n{}",
+                    lines.join("\n")
+                )))
+            }
+        }
+    }
+
+    /// The box with the most volume, the first of them if there's a tie.
+    pub fn largest(&self) -> Option<&OrderLine> {
+        self.boxes.iter().rev().max_by_key(|b| b.volume)
+    }
+
+    /// The box with the least volume, the first of them if there's a tie.
+    pub fn smallest(&self) -> Option<&OrderLine> {
+        self.boxes.iter().min_by_key(|b| b.volume)
+    }
+
+    /// The lines of every group of boxes with the same dimensions in any
+    /// orientation, in the order each group first appears.
+    pub fn duplicates(&self) -> Vec<Vec<usize>> {
+        let mut groups: Vec<Vec<usize>> = Vec::new();
+        let mut group_of_shape = HashMap::new();
+        for order_line in &self.boxes {
+            let mut shape = order_line.dimensions;
+            shape.sort();
+            let group = *group_of_shape.entry(shape).or_insert_with(|| {
+                groups.push(Vec::new());
+                groups.len() - 1
+            });
+            groups[group].push(order_line.line);
+        }
+        groups.retain(|lines| lines.len() > 1);
+        groups
+    }
+
+    /// One row per box with a header, for a spreadsheet.
+    pub fn to_csv(&self) -> String {
+        let mut csv = String::from("line,length,width,height,paper,ribbon\n");
+        for b in &self.boxes {
+            let [l, w, h] = b.dimensions;
+            csv += &format!("{},{l},{w},{h},{},{}\n", b.line, b.paper, b.ribbon);
+        }
+        csv
+    }
+
+    /// Part 1's answer with the rest of the report.
+    fn paper_order(&self) -> PaperOrder {
+        PaperOrder {
+            total: self.paper,
+            boxes: self.boxes.len(),
+            largest: self.largest().cloned(),
+            smallest: self.smallest().cloned(),
+            duplicates: self.duplicates(),
+        }
+    }
+}
+
+/// Part 1's answer, displayed as the total paper.
+pub struct PaperOrder {
+    pub total: u128,
+    pub boxes: usize,
+    pub largest: Option<OrderLine>,
+    pub smallest: Option<OrderLine>,
+    pub duplicates: Vec<Vec<usize>>,
+}
+
+impl std::fmt::Display for PaperOrder {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.total)
+    }
+}
+
+#[derive(Clone)]
+pub struct Day02;
+
+impl Solution for Day02 {
+    type Input<'a> = OrderReport;
+    type Output1 = PaperOrder;
+    type Output2 = u128;
+
+    const PUZZLE_SIZE: usize = 1_000;
+
+    fn parse<'a>(&self, input: &'a PuzzleInput) -> Result<Self::Input<'a>, Error> {
+        OrderReport::parse(input)
+    }
+
+    fn part_1(&self, order: &OrderReport) -> PaperOrder {
+        order.paper_order()
+    }
+
+    fn part_2(&self, order: &OrderReport) -> u128 {
+        order.ribbon
+    }
+
+    fn details_1(&self, order: &PaperOrder) -> Option<Value> {
+        let summary = |order_line: &Option<OrderLine>| {
+            order_line
+                .as_ref()
+                .map(|b| json!({ "line": b.line, "dimensions": b.dimensions }))
+        };
+        Some(json!({
+            "boxes": order.boxes,
+            "largest": summary(&order.largest),
+            "smallest": summary(&order.smallest),
+            "duplicates": order.duplicates,
+        }))
+    }
+
+    /// `size` boxes with sides of 1 to 30.
+    fn generate(&self, rng: &mut Rng, size: usize) -> PuzzleInput {
+        let boxes: Vec<String> = (0..size)
+            .map(|_| {
+                let [l, w, h] = [(); 3].map(|_| rng.range(1..=30));
+                format!("{l}x{w}x{h}")
+            })
+            .collect();
+        PuzzleInput::new(boxes.join("\n"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{answers, part_1, part_2};
+
+    #[test]
+    fn wrapping_paper() {
+        assert_eq!(part_1(&Day02, "2x3x4").total, 58);
+        assert_eq!(part_1(&Day02, "1x1x10").total, 43);
+        assert_eq!(part_1(&Day02, "2x3x4\n1x1x10").total, 101);
+    }
+
+    #[test]
+    fn ribbon() {
+        assert_eq!(part_2(&Day02, "2x3x4"), 34);
+        assert_eq!(part_2(&Day02, "1x1x10"), 14);
+        assert_eq!(part_2(&Day02, "2x3x4\n1x1x10"), 48);
+    }
+
+    #[test]
+    fn order_report() {
+        let order = OrderReport::parse("2x3x4\n1x1x10\n4x2x3\n1x1x1").unwrap();
+        assert_eq!(
+            (order.paper, order.ribbon),
+            (58 + 43 + 58 + 7, 34 + 14 + 34 + 5)
+        );
+        assert_eq!(order.largest().unwrap().line, 1);
+        assert_eq!(order.smallest().unwrap().dimensions, [1, 1, 1]);
+        assert_eq!(order.duplicates(), [[1, 3]]);
+        assert_eq!(
+            order.to_csv(),
+            "line,length,width,height,paper,ribbon\n\
+             1,2,3,4,58,34\n2,1,1,10,43,14\n3,4,2,3,58,34\n4,1,1,1,7,5\n"
+        );
+    }
+
+    #[test]
+    fn reports_every_invalid_line() {
+        let error = OrderReport::parse("2x3x4\n2x3\n1x1x10\n2xax4").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "2 invalid lines:\n  line 2: Expected a box as LxWxH: \"2x3\"\n  \
+             line 4: Invalid dimension: \"a\""
+        );
+
+        let error = Day02.parse(&PuzzleInput::new("2x3x4\n2x3")).unwrap_err();
+        assert_eq!(error.message(), "Expected a box as LxWxH");
+    }
+
+    #[test]
+    fn huge_boxes() {
+        let huge = u64::MAX / 8;
+        let order = OrderReport::parse(&format!("{huge}x1x1\n{huge}x1x1")).unwrap();
+        assert_eq!(order.paper, 2 * (4 * u128::from(huge) + 3));
+
+        let error = OrderReport::parse(&format!("{huge}x{huge}x1")).unwrap_err();
+        assert_eq!(error.message(), "Box is too large to wrap");
+    }
+
+    #[test]
+    fn answers_match() {
+        assert_eq!(answers(&Day02, 2), ["1588178", "3783758"]);
+    }
+}
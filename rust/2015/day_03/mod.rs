@@ -0,0 +1,449 @@
+use crate::{
+    grid::{Direction, Point, SparseGrid},
+    input::PuzzleInput,
+    parse::parse_number,
+    random::Rng,
+    Error, Solution,
+};
+use serde_json::{json, Value};
+use std::str::FromStr;
+
+fn parse_directions(input: &str) -> Result<Vec<Direction>, Error> {
+    input
+        .trim_end()
+        .char_indices()
+        .map(|(i, c)| {
+            Direction::from_arrow(c).ok_or_else(|| {
+                Error::at(&input[i..i + c.len_utf8()], "Expected one of ^, v, < or >")
+            })
+        })
+        .collect()
+}
+
+/// How many times each house was visited.
+pub type Visits = SparseGrid<u32>;
+
+struct Santa {
+    house: Point,
+    visits: Visits,
+    route: Vec<Point>,
+}
+
+impl Santa {
+    fn new() -> Self {
+        let mut visits = Visits::new();
+        visits.insert(Point::ORIGIN, 1);
+        Self {
+            house: Point::ORIGIN,
+            visits,
+            route: vec![Point::ORIGIN],
+        }
+    }
+
+    fn move_direction(&mut self, direction: Direction) {
+        self.house = self.house.step(direction);
+        *self.visits.get_or_default(self.house) += 1;
+        self.route.push(self.house);
+    }
+}
+
+/// The most agents, and the longest cycle of turns, a [`TurnPolicy`] can
+/// have. Both cost memory up front, however few directions there are.
+const MAX_TURNS: usize = 10_000;
+
+/// Who follows each direction. Every agent starts at the origin, so one that
+/// never gets a turn still delivers there.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TurnPolicy {
+    /// This many agents take one direction each in turn.
+    RoundRobin(usize),
+    /// Each agent takes as many directions in a row as its weight, in turn.
+    Weighted(Vec<u32>),
+    /// The agents take the directions in this order, repeated; there are as
+    /// many agents as the highest one plus one.
+    Pattern(Vec<usize>),
+}
+
+impl TurnPolicy {
+    /// Reads `round-robin:<agents>`, `weighted:<weight>,...` or
+    /// `pattern:<agent>,...`, with agents counted from 0.
+    pub fn parse(text: &str) -> Result<Self, Error> {
+        let policy = match text.split_once(':') {
+            Some(("round-robin", agents)) => {
+                TurnPolicy::RoundRobin(parse_number(agents, "number of agents")?)
+            }
+            Some(("weighted", weights)) => TurnPolicy::Weighted(parse_list(weights, "weight")?),
+            Some(("pattern", agents)) => TurnPolicy::Pattern(parse_list(agents, "agent")?),
+            _ => {
+                return Err(Error::at(
+                    text,
+                    "Expected round-robin:<agents>, weighted:<weights> or pattern:<agents>",
+                ))
+            }
+        };
+        if policy.agents() > MAX_TURNS {
+            return Err(Error::at(
+                text,
+                format!("At most {MAX_TURNS} agents can deliver"),
+            ));
+        }
+        match policy.cycle_length() {
+            0 => Err(Error::at(text, "No agent ever gets a turn")),
+            length if length > MAX_TURNS as u64 => Err(Error::at(
+                text,
+                format!("Turns must repeat within {MAX_TURNS} directions"),
+            )),
+            _ => Ok(policy),
+        }
+    }
+
+    pub fn agents(&self) -> usize {
+        match self {
+            TurnPolicy::RoundRobin(agents) => *agents,
+            TurnPolicy::Weighted(weights) => weights.len(),
+            TurnPolicy::Pattern(pattern) => pattern.iter().max().map_or(0, |&agent| agent + 1),
+        }
+    }
+
+    /// How many turns [`order`](Self::order) gives, without listing them.
+    fn cycle_length(&self) -> u64 {
+        match self {
+            TurnPolicy::RoundRobin(agents) => *agents as u64,
+            TurnPolicy::Weighted(weights) => weights.iter().map(|&weight| u64::from(weight)).sum(),
+            TurnPolicy::Pattern(pattern) => pattern.len() as u64,
+        }
+    }
+
+    /// One cycle of turns, as agent indices.
+    fn order(&self) -> Vec<usize> {
+        match self {
+            TurnPolicy::RoundRobin(agents) => (0..*agents).collect(),
+            TurnPolicy::Weighted(weights) => weights
+                .iter()
+                .enumerate()
+                .flat_map(|(agent, &weight)| std::iter::repeat_n(agent, weight as usize))
+                .collect(),
+            TurnPolicy::Pattern(pattern) => pattern.clone(),
+        }
+    }
+}
+
+fn parse_list<T: FromStr>(list: &str, what: &str) -> Result<Vec<T>, Error> {
+    list.split(',').map(|n| parse_number(n, what)).collect()
+}
+
+/// How many pixels across each house is in [`Delivery::to_svg`].
+const SVG_HOUSE_SIZE: i32 = 8;
+
+/// Where every agent went.
+pub struct Delivery {
+    /// Each agent's own visits.
+    pub agents: Vec<Visits>,
+    /// Everyone's visits together.
+    pub visits: Visits,
+    /// The houses each agent went to in order, starting at the origin.
+    pub routes: Vec<Vec<Point>>,
+}
+
+impl Delivery {
+    /// Hands out `directions` to the agents of `policy`.
+    pub fn new(directions: &[Direction], policy: &TurnPolicy) -> Self {
+        let order = policy.order();
+        assert!(!order.is_empty(), "No agent ever gets a turn");
+        let mut agents: Vec<Santa> = (0..policy.agents()).map(|_| Santa::new()).collect();
+        for (&direction, &agent) in directions.iter().zip(order.iter().cycle()) {
+            agents[agent].move_direction(direction);
+        }
+
+        let mut visits = Visits::new();
+        for agent in &agents {
+            for (house, &count) in agent.visits.iter() {
+                *visits.get_or_default(house) += count;
+            }
+        }
+        let (agents, routes) = agents
+            .into_iter()
+            .map(|agent| (agent.visits, agent.route))
+            .unzip();
+        Delivery {
+            agents,
+            visits,
+            routes,
+        }
+    }
+
+    /// The visits as a plain (P2) greyscale image of the bounding box, one
+    /// pixel per house, brighter the more presents it got.
+    pub fn to_pgm(&self) -> String {
+        self.heatmap("P2", |heat| heat.to_string())
+    }
+
+    /// The visits as a plain (P3) colour image of the bounding box, one pixel
+    /// per house, going from dark red through yellow to white the more
+    /// presents it got.
+    pub fn to_ppm(&self) -> String {
+        self.heatmap("P3", |heat| {
+            // Each channel takes its turn to fill up over a third of the scale
+            let channel = |third: u32| (heat * 3).saturating_sub(third * 255).min(255);
+            match heat {
+                0 => "0 0 0".to_string(),
+                _ => format!("{} {} {}", channel(0).max(64), channel(1), channel(2)),
+            }
+        })
+    }
+
+    /// A netpbm image with a header for `magic`, writing each pixel with `pixel`
+    /// from its heat between 0 (unvisited) and 255 (the busiest house).
+    fn heatmap(&self, magic: &str, pixel: impl Fn(u32) -> String) -> String {
+        let (min, max) = self.visits.bounds().unwrap_or_default();
+        let most_visits = self.houses().most_visits.max(1);
+        let mut image = format!(
+            "{magic}\n{} {}\n255\n",
+            max.x - min.x + 1,
+            max.y - min.y + 1
+        );
+        for y in min.y..=max.y {
+            let row: Vec<String> = (min.x..=max.x)
+                .map(|x| {
+                    let visits = self.visits.get(Point::new(x, y)).copied().unwrap_or(0);
+                    pixel((u64::from(visits) * 255 / u64::from(most_visits)) as u32)
+                })
+                .collect();
+            image += &row.join(" ");
+            image.push('\n');
+        }
+        image
+    }
+
+    /// Every agent's route as an SVG polyline of its own colour. The view box
+    /// is the bounding box of the routes, with half a house to spare around it,
+    /// and each house is `SVG_HOUSE_SIZE` pixels across.
+    pub fn to_svg(&self) -> String {
+        const COLOURS: [&str; 6] = [
+            "#c0392b", "#27ae60", "#2980b9", "#8e44ad", "#d35400", "#16a085",
+        ];
+        let (min, max) = self.visits.bounds().unwrap_or_default();
+        let (width, height) = (max.x - min.x + 1, max.y - min.y + 1);
+        let mut svg = format!(
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{}\" height=\"{}\" \
+             viewBox=\"{} {} {width} {height}\">\n",
+            width * SVG_HOUSE_SIZE,
+            height * SVG_HOUSE_SIZE,
+            f64::from(min.x) - 0.5,
+            f64::from(min.y) - 0.5,
+        );
+        for (agent, route) in self.routes.iter().enumerate() {
+            let points: Vec<String> = route
+                .iter()
+                .map(|house| format!("{},{}", house.x, house.y))
+                .collect();
+            svg += &format!(
+                "  <polyline points=\"{}\" fill=\"none\" stroke=\"{}\" \
+                 stroke-width=\"0.2\" stroke-linejoin=\"round\"/>\n",
+                points.join(" "),
+                COLOURS[agent % COLOURS.len()]
+            );
+        }
+        svg += "</svg>\n";
+        svg
+    }
+
+    fn houses(&self) -> Houses {
+        Houses {
+            combined: self.visits.len(),
+            per_agent: self.agents.iter().map(Visits::len).collect(),
+            most_visits: self
+                .visits
+                .iter()
+                .map(|(_, &count)| count)
+                .max()
+                .unwrap_or(0),
+        }
+    }
+}
+
+/// Each part's answer, displayed as the number of houses that got a present.
+pub struct Houses {
+    pub combined: usize,
+    pub per_agent: Vec<usize>,
+    /// How many presents the busiest house got.
+    pub most_visits: u32,
+}
+
+impl std::fmt::Display for Houses {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.combined)
+    }
+}
+
+/// How part 2 shares out the directions; part 1 is always Santa alone.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Config {
+    pub turns: TurnPolicy,
+}
+
+impl Config {
+    pub const PUZZLE: Config = Config {
+        turns: TurnPolicy::RoundRobin(2),
+    };
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config::PUZZLE
+    }
+}
+
+#[derive(Clone, Default)]
+pub struct Day03 {
+    pub config: Config,
+}
+
+impl Day03 {
+    pub const PUZZLE: Day03 = Day03 {
+        config: Config::PUZZLE,
+    };
+}
+
+impl Solution for Day03 {
+    type Input<'a> = Vec<Direction>;
+    type Output1 = Houses;
+    type Output2 = Houses;
+
+    const PUZZLE_SIZE: usize = 8_192;
+    const PARAMETERS: &'static [&'static str] = &["turns"];
+
+    fn parse<'a>(&self, input: &'a PuzzleInput) -> Result<Self::Input<'a>, Error> {
+        parse_directions(input)
+    }
+
+    fn part_1(&self, directions: &Vec<Direction>) -> Houses {
+        Delivery::new(directions, &TurnPolicy::RoundRobin(1)).houses()
+    }
+
+    fn part_2(&self, directions: &Vec<Direction>) -> Houses {
+        Delivery::new(directions, &self.config.turns).houses()
+    }
+
+    fn details_1(&self, houses: &Houses) -> Option<Value> {
+        Some(json!({ "most_visits": houses.most_visits }))
+    }
+
+    fn details_2(&self, houses: &Houses) -> Option<Value> {
+        Some(json!({ "per_agent": houses.per_agent, "most_visits": houses.most_visits }))
+    }
+
+    /// `size` random moves.
+    fn generate(&self, rng: &mut Rng, size: usize) -> PuzzleInput {
+        PuzzleInput::new(
+            (0..size)
+                .map(|_| *rng.choose(&['^', '>', 'v', '<']))
+                .collect::<String>(),
+        )
+    }
+
+    fn configure(&mut self, _name: &str, value: &str) -> Result<(), Error> {
+        self.config.turns = TurnPolicy::parse(value)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{answers, part_1, part_2};
+
+    #[test]
+    fn houses_visited_by_santa() {
+        assert_eq!(part_1(&Day03::PUZZLE, ">").combined, 2);
+        assert_eq!(part_1(&Day03::PUZZLE, "^>v<").combined, 4);
+        assert_eq!(part_1(&Day03::PUZZLE, "^v^v^v^v^v").combined, 2);
+    }
+
+    #[test]
+    fn houses_visited_with_robo_santa() {
+        assert_eq!(part_2(&Day03::PUZZLE, "^v").combined, 3);
+        assert_eq!(part_2(&Day03::PUZZLE, "^>v<").combined, 3);
+        assert_eq!(part_2(&Day03::PUZZLE, "^v^v^v^v^v").combined, 11);
+    }
+
+    #[test]
+    fn turn_policies() {
+        let directions = parse_directions("^>v<^^").unwrap();
+        let delivery = Delivery::new(&directions, &TurnPolicy::RoundRobin(3));
+        let per_agent: Vec<usize> = delivery.agents.iter().map(Visits::len).collect();
+        assert_eq!(per_agent, [3, 3, 2]);
+        assert_eq!(delivery.visits.get(Point::ORIGIN), Some(&4));
+
+        let weighted = Delivery::new(&directions, &TurnPolicy::Weighted(vec![2, 1])).houses();
+        assert_eq!(weighted.per_agent, [4, 2]);
+        assert_eq!(weighted.combined, 5);
+
+        let pattern = Delivery::new(&directions, &TurnPolicy::Pattern(vec![0, 0, 2])).houses();
+        assert_eq!(pattern.per_agent, [4, 1, 2]);
+        assert_eq!(pattern.most_visits, 4);
+    }
+
+    #[test]
+    fn configured_turns() {
+        let day = |turns| Day03 {
+            config: Config {
+                turns: TurnPolicy::parse(turns).unwrap(),
+            },
+        };
+        assert_eq!(part_2(&day("round-robin:1"), "^v^v^v^v^v").combined, 2);
+        assert_eq!(part_2(&day("weighted:1,1"), "^v^v^v^v^v").combined, 11);
+        assert_eq!(part_2(&day("pattern:0,1,1"), "^>v<").combined, 5);
+
+        let error = |turns| TurnPolicy::parse(turns).unwrap_err().message().to_string();
+        assert_eq!(error("weighted:0,0"), "No agent ever gets a turn");
+        assert_eq!(error("pattern:0,x"), "Invalid agent");
+        assert_eq!(
+            error("round-robin:1000000000"),
+            "At most 10000 agents can deliver"
+        );
+        assert_eq!(error("pattern:10000"), "At most 10000 agents can deliver");
+        assert_eq!(
+            error("weighted:4000000000,1"),
+            "Turns must repeat within 10000 directions"
+        );
+        assert_eq!(
+            error("everyone"),
+            "Expected round-robin:<agents>, weighted:<weights> or pattern:<agents>"
+        );
+    }
+
+    #[test]
+    fn images() {
+        let directions = parse_directions("^>v>").unwrap();
+        let delivery = Delivery::new(&directions, &TurnPolicy::RoundRobin(2));
+        assert_eq!(
+            delivery.routes[0],
+            [Point::ORIGIN, Point::new(0, -1), Point::new(0, 0)]
+        );
+        assert_eq!(
+            delivery.routes[1],
+            [Point::ORIGIN, Point::new(1, 0), Point::new(2, 0)]
+        );
+
+        assert_eq!(delivery.to_pgm(), "P2\n3 2\n255\n85 0 0\n255 85 85\n");
+        assert_eq!(
+            delivery.to_ppm(),
+            "P3\n3 2\n255\n255 0 0 0 0 0 0 0 0\n255 255 255 255 0 0 255 0 0\n"
+        );
+        assert_eq!(
+            delivery.to_svg(),
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"24\" height=\"16\" \
+             viewBox=\"-0.5 -1.5 3 2\">\n  \
+             <polyline points=\"0,0 0,-1 0,0\" fill=\"none\" stroke=\"#c0392b\" \
+             stroke-width=\"0.2\" stroke-linejoin=\"round\"/>\n  \
+             <polyline points=\"0,0 1,0 2,0\" fill=\"none\" stroke=\"#27ae60\" \
+             stroke-width=\"0.2\" stroke-linejoin=\"round\"/>\n</svg>\n"
+        );
+    }
+
+    #[test]
+    fn answers_match() {
+        assert_eq!(answers(&Day03::PUZZLE, 3), ["2572", "2631"]);
+    }
+}
@@ -0,0 +1,416 @@
+use crate::{input::PuzzleInput, progress::Progress, random::Rng, Error, Found, Solution};
+use md5::{Context, Digest};
+use std::{
+    collections::BTreeSet,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Mutex,
+    },
+    thread,
+};
+
+/// How many numbers a worker claims at a time.
+const BLOCK_SIZE: usize = 10_000;
+
+/// What a digest must look like to count as mined.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DigestPredicate {
+    /// At least this many leading zero bits; four to each leading hex zero.
+    LeadingZeroBits(u32),
+    /// The hex form starts with this, in lower case.
+    HexPrefix(String),
+    /// The hex digit at `position`, counted from 0, has the value `digit`.
+    DigitAt { position: usize, digit: u8 },
+}
+
+impl DigestPredicate {
+    /// An error if no digest can match, which would leave a search running
+    /// forever.
+    pub fn check(&self) -> Result<(), Error> {
+        match self {
+            DigestPredicate::LeadingZeroBits(bits) if *bits > 128 => Err(Error::new(format!(
+                "Digests have 128 bits, so they can't start with {bits} zero bits"
+            ))),
+            DigestPredicate::HexPrefix(prefix) if prefix.len() > 32 => Err(Error::at(
+                prefix,
+                "Digests have 32 hex digits, so no prefix can be longer",
+            )),
+            DigestPredicate::HexPrefix(prefix) => match prefix
+                .char_indices()
+                .find(|(_, c)| !matches!(c, '0'..='9' | 'a'..='f'))
+            {
+                Some((i, c)) => Err(Error::at(
+                    &prefix[i..i + c.len_utf8()],
+                    "Expected a lower case hex digit",
+                )),
+                None => Ok(()),
+            },
+            DigestPredicate::DigitAt { position, .. } if *position >= 32 => Err(Error::new(
+                format!("Digests have 32 hex digits, so there is no digit at {position}"),
+            )),
+            DigestPredicate::DigitAt { digit, .. } if *digit >= 16 => {
+                Err(Error::new(format!("Hex digits go up to 15, not {digit}")))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Checks the raw bytes, without writing out the hex form.
+    pub fn matches(&self, digest: &Digest) -> bool {
+        match self {
+            DigestPredicate::LeadingZeroBits(bits) => {
+                u128::from_be_bytes(digest.0).leading_zeros() >= *bits
+            }
+            DigestPredicate::HexPrefix(prefix) => {
+                prefix.len() <= 32
+                    && prefix.chars().enumerate().all(|(position, c)| {
+                        c.to_digit(16).map(|digit| digit as u8) == Some(nibble(digest, position))
+                            && !c.is_ascii_uppercase()
+                    })
+            }
+            DigestPredicate::DigitAt { position, digit } => {
+                *position < 32 && nibble(digest, *position) == *digit
+            }
+        }
+    }
+}
+
+/// The hex digit at `position` of `digest`.
+fn nibble(digest: &Digest, position: usize) -> u8 {
+    let byte = digest.0[position / 2];
+    if position.is_multiple_of(2) {
+        byte >> 4
+    } else {
+        byte & 0xF
+    }
+}
+
+/// Looks for numbers whose MD5 digest, after a secret key, matches a predicate.
+pub struct Miner {
+    /// The secret key, already consumed.
+    key: Context,
+    predicate: DigestPredicate,
+}
+
+impl Miner {
+    /// An error if `predicate` can't match any digest.
+    pub fn new(secret_key: &str, predicate: DigestPredicate) -> Result<Self, Error> {
+        predicate.check()?;
+        let mut key = Context::new();
+        key.consume(secret_key);
+        Ok(Miner { key, predicate })
+    }
+
+    /// The digest of the secret key followed by `number` in decimal.
+    pub fn digest(&self, number: usize) -> Digest {
+        let mut digits = [0; 20];
+        let mut start = digits.len();
+        let mut rest = number;
+        loop {
+            start -= 1;
+            digits[start] = b'0' + (rest % 10) as u8;
+            rest /= 10;
+            if rest == 0 {
+                break;
+            }
+        }
+        let mut context = self.key.clone();
+        context.consume(&digits[start..]);
+        context.compute()
+    }
+
+    /// The smallest matching number from `start` on, and its digest, or
+    /// `None` if there is none below `usize::MAX`. Only a search that runs out
+    /// of numbers returns `None`, so this can take as long as it takes.
+    pub fn find(&self, start: usize) -> Option<(usize, Digest)> {
+        self.find_with_checkpoints(start, |_| {})
+    }
+
+    /// Like [`find`](Self::find), but calls `checkpoint` with a number to
+    /// resume from each time every number below it has been tried, which is
+    /// about every [`BLOCK_SIZE`] numbers. Saving the last one lets a search
+    /// that gets interrupted carry on from there.
+    ///
+    /// Every available core runs a worker, and the workers claim blocks of
+    /// numbers in increasing order. A worker stops once the blocks left start
+    /// past the best match so far, so every number below the match has been
+    /// tried whichever worker finds it, and the answer doesn't depend on the
+    /// scheduling.
+    pub fn find_with_checkpoints(
+        &self,
+        start: usize,
+        checkpoint: impl Fn(usize) + Sync,
+    ) -> Option<(usize, Digest)> {
+        let workers = thread::available_parallelism().map_or(1, |n| n.get());
+        let next_block = AtomicUsize::new(start);
+        let best = AtomicUsize::new(usize::MAX);
+        // Every number below the first is tried, and so are the blocks
+        // starting at the rest
+        let tried = Mutex::new((start, BTreeSet::new()));
+        let progress = Progress::new("Mining");
+        // The last block ends at usize::MAX, and then there are none left
+        let claim_block = || {
+            next_block.fetch_update(Ordering::Relaxed, Ordering::Relaxed, |start| {
+                (start < usize::MAX).then(|| start.saturating_add(BLOCK_SIZE))
+            })
+        };
+
+        thread::scope(|scope| {
+            for _ in 0..workers {
+                scope.spawn(|| {
+                    while let Ok(start) = claim_block() {
+                        if start >= best.load(Ordering::Relaxed) {
+                            break;
+                        }
+                        let end = start.saturating_add(BLOCK_SIZE);
+                        if let Some(number) = (start..end)
+                            .find(|&number| self.predicate.matches(&self.digest(number)))
+                        {
+                            best.fetch_min(number, Ordering::Relaxed);
+                            break;
+                        }
+                        progress.add_with((end - start) as u64, || format!("trying {end}"));
+
+                        let mut tried = tried.lock().unwrap();
+                        let (below, blocks) = &mut *tried;
+                        blocks.insert(start);
+                        if *below == start {
+                            while blocks.remove(below) {
+                                *below = below.saturating_add(BLOCK_SIZE);
+                            }
+                            checkpoint(*below);
+                        }
+                    }
+                });
+            }
+        });
+        progress.finish();
+
+        let number = best.into_inner();
+        (number < usize::MAX).then(|| (number, self.digest(number)))
+    }
+
+    /// Every match from `checkpoint` on, in order; `take(k)` gives the first
+    /// `k`. A search stopped part way can carry on from the iterator's
+    /// [`checkpoint`](Matches::checkpoint).
+    pub fn matches(&self, checkpoint: usize) -> Matches<'_> {
+        Matches {
+            miner: self,
+            next: Some(checkpoint),
+        }
+    }
+}
+
+/// The matches of a [`Miner`], as `(number, digest)`.
+pub struct Matches<'m> {
+    miner: &'m Miner,
+    /// `None` once the search has run out of numbers.
+    next: Option<usize>,
+}
+
+impl Matches<'_> {
+    /// Where to resume the search to get the matches this iterator hasn't
+    /// yielded yet.
+    pub fn checkpoint(&self) -> usize {
+        self.next.unwrap_or(usize::MAX)
+    }
+}
+
+impl Iterator for Matches<'_> {
+    type Item = (usize, Digest);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let found = self.miner.find(self.next?);
+        self.next = found.and_then(|(number, _)| number.checked_add(1));
+        found
+    }
+}
+
+const NO_NUMBER: &str = "no number below usize::MAX is lucky";
+
+/// Mines `secret_key` for `bits` leading zero bits, from `start` on.
+fn mine(secret_key: &str, bits: u32, start: usize) -> Found<usize> {
+    let miner = Miner::new(secret_key, DigestPredicate::LeadingZeroBits(bits))
+        .expect("Digests have enough bits");
+    Found::from_option(miner.find(start).map(|(number, _)| number), NO_NUMBER)
+}
+
+#[derive(Clone)]
+pub struct Day04;
+
+impl Solution for Day04 {
+    type Input<'a> = &'a str;
+    type Output1 = Found<usize>;
+    type Output2 = Found<usize>;
+
+    const PUZZLE_SIZE: usize = 8;
+
+    fn parse<'a>(&self, input: &'a PuzzleInput) -> Result<Self::Input<'a>, Error> {
+        Ok(input.trim())
+    }
+
+    fn part_1(&self, secret_key: &&str) -> Found<usize> {
+        mine(secret_key, 20, 0)
+    }
+
+    /// Starts again from 0 rather than from part 1's answer, so each part's
+    /// time is its own whole search.
+    fn part_2(&self, secret_key: &&str) -> Found<usize> {
+        mine(secret_key, 24, 0)
+    }
+
+    /// A secret key of `size` random letters. Such keys can take far longer
+    /// to mine than ours, so the search above has no upper bound.
+    fn generate(&self, rng: &mut Rng, size: usize) -> PuzzleInput {
+        PuzzleInput::new((0..size).map(|_| rng.letter()).collect::<String>())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{answers, part_1};
+
+    #[test]
+    fn five_zeroes() {
+        assert_eq!(part_1(&Day04, "abcdef"), Found::Answer(609043));
+        assert_eq!(part_1(&Day04, "pqrstuv"), Found::Answer(1048970));
+    }
+
+    #[test]
+    fn search_resumes_from_starting_number() {
+        let miner = Miner::new("abcdef", DigestPredicate::LeadingZeroBits(20)).unwrap();
+        let (number, digest) = miner.find(609043).unwrap();
+        assert_eq!(number, 609043);
+        assert!(format!("{digest:?}").starts_with("000001dbbfa"));
+        assert_eq!(miner.digest(1234567890), md5::compute("abcdef1234567890"));
+    }
+
+    #[test]
+    fn predicates() {
+        let digest = md5::compute("abcdef609043");
+        let matches = |predicate: DigestPredicate| predicate.matches(&digest);
+        assert!(matches(DigestPredicate::LeadingZeroBits(23)));
+        assert!(!matches(DigestPredicate::LeadingZeroBits(24)));
+        assert!(matches(DigestPredicate::HexPrefix(
+            "000001dbbfa".to_string()
+        )));
+        assert!(!matches(DigestPredicate::HexPrefix(
+            "000001DBBFA".to_string()
+        )));
+        assert!(!matches(DigestPredicate::HexPrefix("000002".to_string())));
+        assert!(matches(DigestPredicate::DigitAt {
+            position: 6,
+            digit: 0xd
+        }));
+        assert!(!matches(DigestPredicate::DigitAt {
+            position: 32,
+            digit: 0
+        }));
+    }
+
+    #[test]
+    fn rejects_predicates_nothing_matches() {
+        let error = |predicate| Miner::new("abc", predicate).err().unwrap().to_string();
+        assert_eq!(
+            error(DigestPredicate::LeadingZeroBits(129)),
+            "Digests have 128 bits, so they can't start with 129 zero bits"
+        );
+        assert_eq!(
+            error(DigestPredicate::HexPrefix("0".repeat(33))),
+            format!(
+                "Digests have 32 hex digits, so no prefix can be longer: \"{}\"",
+                "0".repeat(33)
+            )
+        );
+        assert_eq!(
+            error(DigestPredicate::HexPrefix("00A".to_string())),
+            "Expected a lower case hex digit: \"A\""
+        );
+        assert_eq!(
+            error(DigestPredicate::DigitAt {
+                position: 32,
+                digit: 0
+            }),
+            "Digests have 32 hex digits, so there is no digit at 32"
+        );
+        assert_eq!(
+            error(DigestPredicate::DigitAt {
+                position: 0,
+                digit: 16
+            }),
+            "Hex digits go up to 15, not 16"
+        );
+    }
+
+    #[test]
+    fn search_ends_at_the_last_number() {
+        let miner = Miner::new("abc", DigestPredicate::LeadingZeroBits(128)).unwrap();
+        assert!(miner.find(usize::MAX - 2 * BLOCK_SIZE).is_none());
+        assert!(miner.matches(usize::MAX - 10).next().is_none());
+    }
+
+    #[test]
+    fn checkpoints_only_pass_tried_numbers() {
+        let miner = Miner::new("abcdef", DigestPredicate::LeadingZeroBits(20)).unwrap();
+        let checkpoints = Mutex::new(Vec::new());
+        let (number, _) = miner
+            .find_with_checkpoints(550_000, |checkpoint| {
+                checkpoints.lock().unwrap().push(checkpoint)
+            })
+            .unwrap();
+        assert_eq!(number, 609043);
+        let checkpoints = checkpoints.into_inner().unwrap();
+        assert_eq!(checkpoints.first(), Some(&560_000));
+        assert!(checkpoints.windows(2).all(|pair| pair[0] < pair[1]));
+        assert!(checkpoints.iter().all(|&checkpoint| checkpoint <= number));
+        assert_eq!(miner.find(*checkpoints.last().unwrap()).unwrap().0, number);
+    }
+
+    #[test]
+    fn finds_the_smallest_match() {
+        // Two zeroes match about one number in 256, so most searches starting
+        // near the end of a block find their match in a later one
+        let miner = Miner::new("xyz", DigestPredicate::HexPrefix("00".to_string())).unwrap();
+        for start in (1..20).map(|i| i * BLOCK_SIZE - 100) {
+            let expected = (start..)
+                .find(|number| {
+                    format!("{:?}", md5::compute(format!("xyz{number}"))).starts_with("00")
+                })
+                .unwrap();
+            assert_eq!(miner.find(start).unwrap().0, expected);
+        }
+    }
+
+    #[test]
+    fn first_matches_and_checkpoints() {
+        let miner = Miner::new(
+            "abc",
+            DigestPredicate::DigitAt {
+                position: 0,
+                digit: 0,
+            },
+        )
+        .unwrap();
+        let first: Vec<usize> = miner.matches(0).take(5).map(|(number, _)| number).collect();
+        let expected: Vec<usize> = (0..)
+            .filter(|number| format!("{:?}", md5::compute(format!("abc{number}"))).starts_with('0'))
+            .take(5)
+            .collect();
+        assert_eq!(first, expected);
+
+        let mut matches = miner.matches(0);
+        matches.by_ref().take(2).for_each(drop);
+        let resumed: Vec<usize> = miner
+            .matches(matches.checkpoint())
+            .take(3)
+            .map(|(number, _)| number)
+            .collect();
+        assert_eq!(resumed, first[2..]);
+    }
+
+    #[test]
+    fn answers_match() {
+        assert_eq!(answers(&Day04, 4), ["282749", "9962624"]);
+    }
+}
@@ -0,0 +1,304 @@
+use crate::{input::PuzzleInput, parse::parse_number, random::Rng, Error, Solution};
+use std::{fs, path::Path};
+
+/// One thing a nice string must have, or mustn't.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Rule {
+    /// At least this many of `aeiou`, counting repeats.
+    Vowels(usize),
+    /// None of these anywhere in it.
+    Forbidden(Vec<String>),
+    /// A letter twice in a row.
+    DoubledLetter,
+    /// Two letters that appear again later on, without overlapping.
+    RepeatedPair,
+    /// A letter repeated with this many letters between, like `xyx` for 1.
+    Sandwich(usize),
+}
+
+impl Rule {
+    pub fn matches(&self, s: &str) -> bool {
+        self.matches_chars(s, &s.chars().collect::<Vec<_>>())
+    }
+
+    /// [`matches`](Self::matches) for `s`, given its chars.
+    fn matches_chars(&self, s: &str, chars: &[char]) -> bool {
+        match self {
+            Rule::Vowels(count) => chars.iter().filter(|&&c| "aeiou".contains(c)).count() >= *count,
+            Rule::Forbidden(forbidden) => !forbidden.iter().any(|f| s.contains(f.as_str())),
+            Rule::DoubledLetter => has_sandwich(chars, 0),
+            Rule::RepeatedPair => (0..chars.len().saturating_sub(3)).any(|i| {
+                chars[i + 2..]
+                    .windows(2)
+                    .any(|pair| pair == &chars[i..i + 2])
+            }),
+            Rule::Sandwich(gap) => has_sandwich(chars, *gap),
+        }
+    }
+}
+
+/// No string is long enough to hold a sandwich whose length overflows.
+fn has_sandwich(chars: &[char], gap: usize) -> bool {
+    gap.checked_add(2)
+        .is_some_and(|length| chars.windows(length).any(|w| w[0] == w[gap + 1]))
+}
+
+/// Rules that a string has to match every one of to be nice.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RuleSet(pub Vec<Rule>);
+
+impl RuleSet {
+    /// Reads one rule per line or between `;`s, ignoring blank lines and the
+    /// rest of a line after a `#`, `;`s included:
+    ///
+    /// ```text
+    /// vowels 3              # at least 3 vowels
+    /// forbidden ab cd pq xy # none of these
+    /// doubled-letter
+    /// repeated-pair
+    /// sandwich 1            # a letter, one other, then the first again
+    /// ```
+    pub fn parse(text: &str) -> Result<Self, Error> {
+        text.lines()
+            .flat_map(|line| line.split('#').next().unwrap_or_default().split(';'))
+            .map(str::trim)
+            .filter(|line| !line.is_empty())
+            .map(|line| {
+                let mut words = line.split_whitespace();
+                let name = words.next().unwrap_or_default();
+                let arguments: Vec<&str> = words.collect();
+                let rule = match (name, arguments.as_slice()) {
+                    ("vowels", [count]) => Rule::Vowels(parse_number(count, "number of vowels")?),
+                    ("forbidden", []) => return Err(Error::at(line, "Expected forbidden strings")),
+                    ("forbidden", strings) => {
+                        Rule::Forbidden(strings.iter().map(|s| s.to_string()).collect())
+                    }
+                    ("doubled-letter", []) => Rule::DoubledLetter,
+                    ("repeated-pair", []) => Rule::RepeatedPair,
+                    ("sandwich", [gap]) => Rule::Sandwich(parse_number(gap, "gap")?),
+                    ("vowels" | "doubled-letter" | "repeated-pair" | "sandwich", _) => {
+                        return Err(Error::at(line, "Wrong number of arguments"))
+                    }
+                    _ => {
+                        return Err(Error::at(
+                            name,
+                            "Expected vowels, forbidden, doubled-letter, repeated-pair or sandwich",
+                        ))
+                    }
+                };
+                Ok(rule)
+            })
+            .collect::<Result<_, _>>()
+            .map(RuleSet)
+    }
+
+    /// Reads rules from a file, as [`parse`](Self::parse) does.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, Error> {
+        let path = path.as_ref();
+        let text = fs::read_to_string(path)
+            .map_err(|e| Error::new(format!("Could not read {}: {e}", path.display())))?;
+        Self::parse(&text)
+    }
+
+    pub fn matches(&self, s: &str) -> bool {
+        let chars: Vec<char> = s.chars().collect();
+        self.0.iter().all(|rule| rule.matches_chars(s, &chars))
+    }
+
+    /// The rules from the puzzle text for part 1.
+    pub fn part_1() -> Self {
+        RuleSet(vec![
+            Rule::Vowels(3),
+            Rule::Forbidden(["ab", "cd", "pq", "xy"].map(String::from).to_vec()),
+            Rule::DoubledLetter,
+        ])
+    }
+
+    /// The rules from the puzzle text for part 2.
+    pub fn part_2() -> Self {
+        RuleSet(vec![Rule::RepeatedPair, Rule::Sandwich(1)])
+    }
+}
+
+fn nice_strings<F>(input: &str, predicate: F) -> usize
+where
+    F: Fn(&str) -> bool,
+{
+    input.lines().filter(|s| predicate(s)).count()
+}
+
+/// Each part's rules; `None` for the puzzle's own.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Config {
+    pub part_1_rules: Option<RuleSet>,
+    pub part_2_rules: Option<RuleSet>,
+}
+
+impl Config {
+    pub const PUZZLE: Config = Config {
+        part_1_rules: None,
+        part_2_rules: None,
+    };
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config::PUZZLE
+    }
+}
+
+#[derive(Clone, Default)]
+pub struct Day05 {
+    pub config: Config,
+}
+
+impl Day05 {
+    pub const PUZZLE: Day05 = Day05 {
+        config: Config::PUZZLE,
+    };
+}
+
+impl Solution for Day05 {
+    type Input<'a> = &'a str;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    const PUZZLE_SIZE: usize = 1_000;
+    const PARAMETERS: &'static [&'static str] = &["part_1_rules", "part_2_rules"];
+
+    fn parse<'a>(&self, input: &'a PuzzleInput) -> Result<Self::Input<'a>, Error> {
+        Ok(input.text())
+    }
+
+    fn part_1(&self, input: &&str) -> usize {
+        let rules = self
+            .config
+            .part_1_rules
+            .clone()
+            .unwrap_or_else(RuleSet::part_1);
+        nice_strings(input, |s| rules.matches(s))
+    }
+
+    fn part_2(&self, input: &&str) -> usize {
+        let rules = self
+            .config
+            .part_2_rules
+            .clone()
+            .unwrap_or_else(RuleSet::part_2);
+        nice_strings(input, |s| rules.matches(s))
+    }
+
+    /// `size` strings of 16 random letters.
+    fn generate(&self, rng: &mut Rng, size: usize) -> PuzzleInput {
+        let strings: Vec<String> = (0..size)
+            .map(|_| (0..16).map(|_| rng.letter()).collect())
+            .collect();
+        PuzzleInput::new(strings.join("\n"))
+    }
+
+    /// Rules are read from a file instead if the value is `@<path>`.
+    fn configure(&mut self, name: &str, value: &str) -> Result<(), Error> {
+        let rules = Some(match value.strip_prefix('@') {
+            Some(path) => RuleSet::load(path)?,
+            None => RuleSet::parse(value)?,
+        });
+        match name {
+            "part_1_rules" => self.config.part_1_rules = rules,
+            _ => self.config.part_2_rules = rules,
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{testing::answers, DynSolution, Part};
+
+    #[test]
+    fn part_1_rules() {
+        let nice = |s| RuleSet::part_1().matches(s);
+        assert!(nice("ugknbfddgicrmopn"));
+        assert!(nice("aaa"));
+        assert!(!nice("jchzalrnumimnmhp"));
+        assert!(!nice("haegwjzuvuyypxyu"));
+        assert!(!nice("dvszwmarrgswjxmb"));
+    }
+
+    #[test]
+    fn part_2_rules() {
+        let nice = |s| RuleSet::part_2().matches(s);
+        assert!(nice("qjhvhtzxzqqjkmpb"));
+        assert!(nice("xxyxx"));
+        assert!(!nice("uurcxstgmygtbstg"));
+        assert!(!nice("ieodomkazucvgmuy"));
+        assert!(!nice("aaa"));
+    }
+
+    #[test]
+    fn rule_files() {
+        let rules = "\
+            # part 1, as in the puzzle
+            vowels 3
+            forbidden ab cd pq xy
+            doubled-letter
+        ";
+        assert_eq!(RuleSet::parse(rules).unwrap(), RuleSet::part_1());
+        assert_eq!(
+            RuleSet::parse("repeated-pair; sandwich 1").unwrap(),
+            RuleSet::part_2()
+        );
+        assert_eq!(
+            RuleSet::parse("vowels 3 # a; b").unwrap(),
+            RuleSet(vec![Rule::Vowels(3)])
+        );
+        assert!(RuleSet::parse("sandwich 3").unwrap().matches("abcda"));
+        assert!(!RuleSet(vec![Rule::Sandwich(usize::MAX)]).matches("abcda"));
+
+        let error = |rules| RuleSet::parse(rules).unwrap_err().to_string();
+        assert_eq!(
+            error("vowels 3\nconsonants 2"),
+            "Expected vowels, forbidden, doubled-letter, repeated-pair or sandwich: \"consonants\""
+        );
+        assert_eq!(error("sandwich"), "Wrong number of arguments: \"sandwich\"");
+        assert_eq!(error("vowels x"), "Invalid number of vowels: \"x\"");
+    }
+
+    #[test]
+    fn configured_rules() {
+        let day = Day05::PUZZLE
+            .configured(&[("part_1_rules".to_string(), "vowels 1".to_string())])
+            .unwrap();
+        let answers = day
+            .solve(&PuzzleInput::new("xyz\nabc\nbcd"), &[Part::One])
+            .unwrap();
+        assert_eq!(answers[0].1, "1");
+
+        let path = std::env::temp_dir().join(format!("day_05_rules_{}.txt", std::process::id()));
+        fs::write(&path, "repeated-pair\nsandwich 0\n").unwrap();
+        let day = Day05::PUZZLE
+            .configured(&[("part_2_rules".to_string(), format!("@{}", path.display()))])
+            .unwrap();
+        fs::remove_file(&path).unwrap();
+        let answers = day
+            .solve(
+                &PuzzleInput::new(
+                    "aabaa
+xyxy",
+                ),
+                &[Part::Two],
+            )
+            .unwrap();
+        assert_eq!(answers[0].1, "1");
+        let error = Day05::PUZZLE
+            .configured(&[("part_2_rules".to_string(), format!("@{}", path.display()))])
+            .err()
+            .unwrap();
+        assert!(error.message().starts_with("Could not read"));
+    }
+
+    #[test]
+    fn answers_match() {
+        assert_eq!(answers(&Day05::PUZZLE, 5), ["236", "51"]);
+    }
+}
@@ -0,0 +1,164 @@
+use crate::{
+    grid::{Grid, Point},
+    input::PuzzleInput,
+    parse::Pattern,
+    random::Rng,
+    Error, Solution,
+};
+
+const GRID_SIZE: usize = 1000;
+
+const COORD: Pattern = Pattern::new("{x},{y}");
+const AREA: Pattern = Pattern::new("{start} through {end}");
+
+#[derive(Debug)]
+enum Command {
+    TurnOn,
+    TurnOff,
+    Toggle,
+}
+
+fn parse_coord(coord: &str) -> Result<Point, Error> {
+    let (x, y) = COORD.parse(coord)?;
+    if !(0..GRID_SIZE as i32).contains(&x) || !(0..GRID_SIZE as i32).contains(&y) {
+        return Err(Error::at(
+            coord,
+            format!("Coordinate is outside the {GRID_SIZE}x{GRID_SIZE} grid"),
+        ));
+    }
+    Ok(Point::new(x, y))
+}
+
+#[derive(Debug)]
+pub struct Instruction {
+    command: Command,
+    start: Point,
+    end: Point,
+}
+
+impl Instruction {
+    fn from_str(line: &str) -> Result<Self, Error> {
+        let (command, coords) = if let Some(coords) = line.strip_prefix("toggle ") {
+            (Command::Toggle, coords)
+        } else if let Some(coords) = line.strip_prefix("turn on ") {
+            (Command::TurnOn, coords)
+        } else if let Some(coords) = line.strip_prefix("turn off ") {
+            (Command::TurnOff, coords)
+        } else {
+            let command = &line[..line
+                .find(|c: char| c.is_ascii_digit())
+                .unwrap_or(line.len())];
+            return Err(Error::at(
+                command.trim_end(),
+                "Expected toggle, turn on or turn off",
+            ));
+        };
+
+        let (start, end) = AREA.parse(coords)?;
+
+        Ok(Instruction {
+            command,
+            start: parse_coord(start)?,
+            end: parse_coord(end)?,
+        })
+    }
+
+    fn get_coords(&self) -> impl Iterator<Item = Point> + '_ {
+        (self.start.x..=self.end.x)
+            .flat_map(move |x| (self.start.y..=self.end.y).map(move |y| Point::new(x, y)))
+    }
+}
+
+#[derive(Clone)]
+pub struct Day06;
+
+impl Solution for Day06 {
+    type Input<'a> = Vec<Instruction>;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    const PUZZLE_SIZE: usize = 300;
+
+    fn parse<'a>(&self, input: &'a PuzzleInput) -> Result<Self::Input<'a>, Error> {
+        input.lines().map(Instruction::from_str).collect()
+    }
+
+    fn part_1(&self, instructions: &Vec<Instruction>) -> usize {
+        let mut light_grid = Grid::new(GRID_SIZE, GRID_SIZE, false);
+        for instruction in instructions {
+            for coord in instruction.get_coords() {
+                let light = &mut light_grid[coord];
+                *light = match instruction.command {
+                    Command::TurnOn => true,
+                    Command::TurnOff => false,
+                    Command::Toggle => !*light,
+                }
+            }
+        }
+
+        light_grid.count_on()
+    }
+
+    fn part_2(&self, instructions: &Vec<Instruction>) -> usize {
+        let mut light_grid: Grid<isize> = Grid::new(GRID_SIZE, GRID_SIZE, 0);
+        for instruction in instructions {
+            for coord in instruction.get_coords() {
+                let brightness = &mut light_grid[coord];
+                *brightness += match instruction.command {
+                    Command::TurnOn => 1,
+                    Command::TurnOff => -1,
+                    Command::Toggle => 2,
+                };
+                *brightness = (*brightness).max(0);
+            }
+        }
+
+        light_grid.values().sum::<isize>() as usize
+    }
+
+    /// `size` instructions, each over a random rectangle.
+    fn generate(&self, rng: &mut Rng, size: usize) -> PuzzleInput {
+        let instructions: Vec<String> = (0..size)
+            .map(|_| {
+                let command = *rng.choose(&["turn on", "turn off", "toggle"]);
+                let [(x1, x2), (y1, y2)] = [(); 2].map(|_| {
+                    let (a, b) = (rng.range(0..=GRID_SIZE - 1), rng.range(0..=GRID_SIZE - 1));
+                    (a.min(b), a.max(b))
+                });
+                format!("{command} {x1},{y1} through {x2},{y2}")
+            })
+            .collect();
+        PuzzleInput::new(instructions.join("\n"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{answers, part_1, part_2};
+
+    #[test]
+    fn lights_lit() {
+        assert_eq!(part_1(&Day06, "turn on 0,0 through 999,999"), 1_000_000);
+        assert_eq!(part_1(&Day06, "toggle 0,0 through 999,0"), 1000);
+        assert_eq!(
+            part_1(
+                &Day06,
+                "turn on 0,0 through 999,999\nturn off 499,499 through 500,500"
+            ),
+            999_996
+        );
+    }
+
+    #[test]
+    fn total_brightness() {
+        assert_eq!(part_2(&Day06, "turn on 0,0 through 0,0"), 1);
+        assert_eq!(part_2(&Day06, "toggle 0,0 through 999,999"), 2_000_000);
+        assert_eq!(part_2(&Day06, "turn off 0,0 through 0,0"), 0);
+    }
+
+    #[test]
+    fn answers_match() {
+        assert_eq!(answers(&Day06, 6), ["543903", "14687245"]);
+    }
+}
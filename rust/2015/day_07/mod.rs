@@ -0,0 +1,282 @@
+use crate::{
+    input::PuzzleInput,
+    parse::{parse_number, Pattern},
+    random::Rng,
+    Error, Solution,
+};
+use std::collections::HashMap;
+
+const WIRE: Pattern = Pattern::new("{gate} -> {wire}");
+
+/// The signals worked out so far. A wire is `None` while its own inputs are
+/// being worked out, so coming back to it means it depends on itself.
+type Signals<'a> = HashMap<&'a str, Option<u16>>;
+
+#[derive(Debug, Clone)]
+enum Input<'a> {
+    Wire(&'a str),
+    Value(u16),
+}
+
+impl<'a> Input<'a> {
+    fn from_str(s: &'a str) -> Input<'a> {
+        s.parse::<u16>()
+            .map(Input::Value)
+            .unwrap_or_else(|_| Input::Wire(s))
+    }
+
+    fn evaluate(
+        &self,
+        wires: &HashMap<&str, Wire<'a>>,
+        signals: &mut Signals<'a>,
+    ) -> Result<u16, Error> {
+        match *self {
+            Input::Value(v) => Ok(v),
+            Input::Wire(w) => match (signals.get(w), wires.get(w)) {
+                (Some(Some(signal)), _) => Ok(*signal),
+                (Some(None), _) => Err(Error::at(w, format!("Wire {w} depends on itself"))),
+                (None, Some(wire)) => wire.evaluate(wires, signals),
+                (None, None) => Err(Error::at(w, format!("No wire {w} in the circuit"))),
+            },
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+enum Gate<'a> {
+    Direct(Input<'a>),
+    And(Input<'a>, Input<'a>),
+    Or(Input<'a>, Input<'a>),
+    Not(Input<'a>),
+    Rshift(Input<'a>, u16),
+    Lshift(Input<'a>, u16),
+}
+
+#[derive(Debug, Clone)]
+pub struct Wire<'a> {
+    gate: Gate<'a>,
+    output: &'a str,
+}
+
+impl<'a> Wire<'a> {
+    fn from_str(line: &'a str) -> Result<Self, Error> {
+        let (gate_str, output): (&str, _) = WIRE.parse(line)?;
+
+        let gate = match gate_str.split_whitespace().collect::<Vec<_>>()[..] {
+            [input] => Gate::Direct(Input::from_str(input)),
+            ["NOT", input] => Gate::Not(Input::from_str(input)),
+            [a, "AND", b] => Gate::And(Input::from_str(a), Input::from_str(b)),
+            [a, "OR", b] => Gate::Or(Input::from_str(a), Input::from_str(b)),
+            [input, "RSHIFT", shift] => Gate::Rshift(Input::from_str(input), parse_shift(shift)?),
+            [input, "LSHIFT", shift] => Gate::Lshift(Input::from_str(input), parse_shift(shift)?),
+            [_, operation, _] => return Err(Error::at(operation, "Invalid operation")),
+            _ => return Err(Error::at(gate_str, "Invalid gate")),
+        };
+        Ok(Wire { gate, output })
+    }
+
+    fn evaluate(
+        &self,
+        wires: &HashMap<&str, Wire<'a>>,
+        signals: &mut Signals<'a>,
+    ) -> Result<u16, Error> {
+        if let Some(Some(signal)) = signals.get(self.output) {
+            return Ok(*signal);
+        }
+        signals.insert(self.output, None);
+
+        let signal = match &self.gate {
+            Gate::Direct(input) => input.evaluate(wires, signals)?,
+            Gate::And(a, b) => a.evaluate(wires, signals)? & b.evaluate(wires, signals)?,
+            Gate::Or(a, b) => a.evaluate(wires, signals)? | b.evaluate(wires, signals)?,
+            Gate::Not(a) => !a.evaluate(wires, signals)?,
+            Gate::Rshift(a, b) => a.evaluate(wires, signals)? >> b,
+            Gate::Lshift(a, b) => a.evaluate(wires, signals)? << b,
+        };
+
+        signals.insert(self.output, Some(signal));
+        Ok(signal)
+    }
+}
+
+/// Signals are 16 bits, so shifting by 16 or more would lose them all.
+fn parse_shift(text: &str) -> Result<u16, Error> {
+    let shift = parse_number(text, "shift value")?;
+    if shift >= 16 {
+        return Err(Error::at(text, "Shifts must be less than 16"));
+    }
+    Ok(shift)
+}
+
+/// The signal on wire `a`, given some signals that are already set.
+/// [`Day07::parse`] checks that there is one.
+fn signal_of_a<'a>(wires: &HashMap<&str, Wire<'a>>, mut signals: Signals<'a>) -> u16 {
+    wires["a"]
+        .evaluate(wires, &mut signals)
+        .expect("The circuit was checked when it was parsed")
+}
+
+/// A distinct wire name for every number: `aa`, `ab`, ..., `zz`, `aaa`, and so
+/// on, which never clash with `a` and `b`.
+fn wire_name(number: usize) -> String {
+    let mut name = Vec::new();
+    let mut rest = number + 27;
+    while rest > 0 {
+        rest -= 1;
+        name.push(b'a' + (rest % 26) as u8);
+        rest /= 26;
+    }
+    name.iter().rev().map(|&c| c as char).collect()
+}
+
+/// A shuffled circuit of `size` wires. Each gate only reads wires defined
+/// before it, so the circuit has no loops; `b` is a plain signal, since part 2
+/// overrides it, and `a` is the last wire.
+fn generate_circuit(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(2);
+    let mut names = vec!["b".to_string()];
+    let mut lines = vec![format!("{} -> b", rng.range(0..=u16::MAX))];
+    for i in 1..size {
+        let name = if i == size - 1 {
+            "a".to_string()
+        } else {
+            wire_name(i - 1)
+        };
+        let (x, y) = (rng.choose(&names).clone(), rng.choose(&names).clone());
+        // Weighted towards the gates that keep bits set, so the signals don't
+        // all drain to 0
+        let gate = match rng.range(0..=8) {
+            0 => x,
+            1 | 2 => format!("NOT {x}"),
+            3 => format!("{x} AND {y}"),
+            // Like our input, which masks single bits with a literal 1
+            4 => format!("1 AND {x}"),
+            5 | 6 => format!("{x} OR {y}"),
+            7 => format!("{x} RSHIFT {}", rng.range(1..=5)),
+            _ => format!("{x} LSHIFT {}", rng.range(1..=5)),
+        };
+        lines.push(format!("{gate} -> {name}"));
+        names.push(name);
+    }
+    rng.shuffle(&mut lines);
+    lines.join("\n")
+}
+
+#[derive(Clone)]
+pub struct Day07;
+
+impl Solution for Day07 {
+    type Input<'a> = HashMap<&'a str, Wire<'a>>;
+    type Output1 = u16;
+    type Output2 = u16;
+
+    const PUZZLE_SIZE: usize = 339;
+
+    /// Also works out wire `a` once, so a missing wire or a loop is an error
+    /// here rather than a panic in the parts. Overriding `b` in part 2 can only
+    /// cut wires out, so `a` still has a signal then.
+    fn parse<'a>(&self, input: &'a PuzzleInput) -> Result<Self::Input<'a>, Error> {
+        let wires: HashMap<_, _> = input
+            .lines()
+            .map(|line| Wire::from_str(line).map(|wire| (wire.output, wire)))
+            .collect::<Result<_, _>>()?;
+        let a = wires
+            .get("a")
+            .ok_or_else(|| Error::new("The circuit has no wire a"))?;
+        a.evaluate(&wires, &mut Signals::new())?;
+        Ok(wires)
+    }
+
+    fn part_1(&self, wires: &HashMap<&str, Wire>) -> u16 {
+        signal_of_a(wires, Signals::new())
+    }
+
+    fn part_2(&self, wires: &HashMap<&str, Wire>) -> u16 {
+        let a_value = self.part_1(wires);
+        signal_of_a(wires, Signals::from([("b", Some(a_value))]))
+    }
+
+    fn generate(&self, rng: &mut Rng, size: usize) -> PuzzleInput {
+        PuzzleInput::new(generate_circuit(rng, size))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::answers;
+
+    const EXAMPLE: &str = "\
+123 -> x
+456 -> y
+x AND y -> d
+x OR y -> e
+x LSHIFT 2 -> f
+y RSHIFT 2 -> g
+NOT x -> h
+NOT y -> i";
+
+    #[test]
+    fn example_circuit() {
+        // The example has no wire a, so it can't go through Day07::parse
+        let wires: HashMap<_, _> = EXAMPLE
+            .lines()
+            .map(|line| Wire::from_str(line).map(|wire| (wire.output, wire)))
+            .collect::<Result<_, _>>()
+            .unwrap();
+        let mut cache = HashMap::new();
+        for (wire, signal) in [
+            ("d", 72),
+            ("e", 507),
+            ("f", 492),
+            ("g", 114),
+            ("h", 65412),
+            ("i", 65079),
+            ("x", 123),
+            ("y", 456),
+        ] {
+            assert_eq!(
+                wires[wire].evaluate(&wires, &mut cache).unwrap(),
+                signal,
+                "{wire}"
+            );
+        }
+    }
+
+    #[test]
+    fn invalid_instruction() {
+        assert!(Wire::from_str("x XOR y -> z").is_err());
+        assert!(Wire::from_str("x AND y").is_err());
+        let error = Wire::from_str("x LSHIFT 16 -> a").unwrap_err();
+        assert_eq!(error.to_string(), "Shifts must be less than 16: \"16\"");
+    }
+
+    #[test]
+    fn unresolved_circuits() {
+        let error = |circuit| {
+            Day07
+                .parse(&PuzzleInput::new(circuit))
+                .unwrap_err()
+                .message()
+                .to_string()
+        };
+        assert_eq!(error("1 -> b"), "The circuit has no wire a");
+        assert_eq!(error("b AND c -> a\n1 -> b"), "No wire c in the circuit");
+        assert_eq!(error("c -> a\nNOT a -> c"), "Wire a depends on itself");
+    }
+
+    #[test]
+    fn generated_circuits_settle() {
+        for seed in 0..5 {
+            let input = Day07.generate(&mut Rng::new(seed), 500);
+            let wires = Day07.parse(&input).unwrap();
+            assert_eq!(wires.len(), 500);
+            Day07.part_2(&wires);
+        }
+    }
+
+    #[test]
+    fn answers_match() {
+        assert_eq!(answers(&Day07, 7), ["46065", "14134"]);
+    }
+}
@@ -0,0 +1,168 @@
+use crate::{input::PuzzleInput, random::Rng, Error, Solution};
+use std::str::Lines;
+
+struct InputString<'a> {
+    string: &'a str,
+}
+
+impl<'a> InputString<'a> {
+    fn input_length(&self) -> usize {
+        self.string.len()
+    }
+
+    fn in_memory_length(&self) -> usize {
+        let mut chars = self.string.trim_matches('"').chars();
+        let mut length = 0;
+        while let Some(c) = chars.next() {
+            if c == '\\' {
+                match chars.next() {
+                    Some('\\') => length += 1,
+                    Some('"') => length += 1,
+                    Some('x') => {
+                        chars.next();
+                        chars.next();
+                        length += 1;
+                    }
+                    _ => length += 1,
+                }
+            } else {
+                length += 1;
+            }
+        }
+        length
+    }
+
+    fn escape_length(&self) -> usize {
+        self.string.escape_default().count() + 2
+    }
+}
+
+/// Checks that `line` is a string literal: in double quotes, with no others
+/// inside unless escaped, and only `\\`, `\"` and `\x` with two hex digits as
+/// escapes.
+fn check_literal(line: &str) -> Result<(), Error> {
+    let body = line
+        .strip_prefix('"')
+        .and_then(|rest| rest.strip_suffix('"'))
+        .ok_or_else(|| Error::at(line, "Expected a string in double quotes"))?;
+    let mut chars = body.char_indices();
+    while let Some((i, c)) = chars.next() {
+        match c {
+            '"' => return Err(Error::at(&body[i..=i], "Unescaped quote")),
+            '\\' => match chars.next() {
+                Some((_, '\\' | '"')) => {}
+                Some((_, 'x'))
+                    if body
+                        .get(i + 2..i + 4)
+                        .is_some_and(|hex| hex.chars().all(|c| c.is_ascii_hexdigit())) =>
+                {
+                    chars.nth(1);
+                }
+                _ => {
+                    let end = body[i + 1..]
+                        .chars()
+                        .next()
+                        .map_or(i + 1, |c| i + 1 + c.len_utf8());
+                    return Err(Error::at(
+                        &body[i..end],
+                        "Expected \\\\, \\\" or \\x and two hex digits",
+                    ));
+                }
+            },
+            _ => {}
+        }
+    }
+    Ok(())
+}
+
+#[derive(Clone)]
+pub struct Day08;
+
+impl Solution for Day08 {
+    type Input<'a> = Lines<'a>;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    const PUZZLE_SIZE: usize = 300;
+
+    fn parse<'a>(&self, input: &'a PuzzleInput) -> Result<Self::Input<'a>, Error> {
+        for line in input.lines() {
+            check_literal(line)?;
+        }
+        Ok(input.lines())
+    }
+
+    fn part_1(&self, input_strings: &Lines) -> usize {
+        input_strings
+            .clone()
+            .map(|s| InputString { string: s })
+            .map(|input_string| input_string.input_length() - input_string.in_memory_length())
+            .sum()
+    }
+
+    fn part_2(&self, input_strings: &Lines) -> usize {
+        input_strings
+            .clone()
+            .map(|s| InputString { string: s })
+            .map(|input_string| input_string.escape_length() - input_string.input_length())
+            .sum()
+    }
+
+    /// `size` string literals of up to 30 characters, using every kind of
+    /// escape.
+    fn generate(&self, rng: &mut Rng, size: usize) -> PuzzleInput {
+        let strings: Vec<String> = (0..size)
+            .map(|_| {
+                let length = rng.range(0..=30);
+                let body: String = (0..length)
+                    .map(|_| match rng.range(0..=9) {
+                        0 => r"\\".to_string(),
+                        1 => r#"\""#.to_string(),
+                        2 => format!(r"\x{:02x}", rng.range(0..=u8::MAX)),
+                        _ => rng.letter().to_string(),
+                    })
+                    .collect();
+                format!("\"{body}\"")
+            })
+            .collect();
+        PuzzleInput::new(strings.join("\n"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{answers, part_1, part_2};
+
+    const EXAMPLE: &str = r#"""
+"abc"
+"aaa\"aaa"
+"\x27""#;
+
+    #[test]
+    fn code_minus_memory() {
+        assert_eq!(part_1(&Day08, EXAMPLE), 12);
+    }
+
+    #[test]
+    fn encoded_minus_code() {
+        assert_eq!(part_2(&Day08, EXAMPLE), 19);
+    }
+
+    #[test]
+    fn rejects_other_lines() {
+        let error = |line| check_literal(line).unwrap_err().to_string();
+        assert_eq!(error("abc"), "Expected a string in double quotes: \"abc\"");
+        assert_eq!(error(r#""a"b""#), "Unescaped quote: \"\\\"\"");
+        assert_eq!(
+            error(r#""a\xzz""#),
+            r#"Expected \\, \" or \x and two hex digits: "\\x""#
+        );
+        assert!(check_literal(r#""\\\"\x4f""#).is_ok());
+    }
+
+    #[test]
+    fn answers_match() {
+        assert_eq!(answers(&Day08, 8), ["1333", "2046"]);
+    }
+}
@@ -0,0 +1,110 @@
+use crate::{
+    graph::{Goal, Graph, MAX_NODES},
+    input::PuzzleInput,
+    parse::Pattern,
+    random::Rng,
+    Error, Found, Solution,
+};
+
+const ROUTE: Pattern = Pattern::new("{from} to {to} = {distance}");
+
+fn parse_distances(input: &str) -> Result<Graph<'_, u16>, Error> {
+    let mut graph = Graph::new();
+    for line in input.lines() {
+        let (from, to, distance) = ROUTE.parse(line)?;
+        graph.add_symmetric_edge(from, to, distance);
+    }
+    Ok(graph)
+}
+
+fn route_distance(graph: &Graph<u16>, goal: Goal) -> Found<u16> {
+    if !graph.is_searchable() {
+        return Found::Nothing("too many locations to search every route");
+    }
+    Found::from_option(
+        graph.best_path(goal).map(|path| path.value),
+        "no route visits every location",
+    )
+}
+
+#[derive(Clone)]
+pub struct Day09;
+
+impl Solution for Day09 {
+    type Input<'a> = Graph<'a, u16>;
+    type Output1 = Found<u16>;
+    type Output2 = Found<u16>;
+
+    const PUZZLE_SIZE: usize = 8;
+
+    fn parse<'a>(&self, input: &'a PuzzleInput) -> Result<Self::Input<'a>, Error> {
+        parse_distances(input)
+    }
+
+    fn part_1(&self, graph: &Graph<u16>) -> Found<u16> {
+        route_distance(graph, Goal::Min)
+    }
+
+    fn part_2(&self, graph: &Graph<u16>) -> Found<u16> {
+        route_distance(graph, Goal::Max)
+    }
+
+    /// Distances of 1 to 200 between every pair of `size` cities, up to as many
+    /// as the route search can handle.
+    fn generate(&self, rng: &mut Rng, size: usize) -> PuzzleInput {
+        let cities = rng.names(size.min(MAX_NODES));
+        let mut routes = Vec::new();
+        for (i, from) in cities.iter().enumerate() {
+            for to in &cities[i + 1..] {
+                routes.push(format!("{from} to {to} = {}", rng.range(1..=200)));
+            }
+        }
+        PuzzleInput::new(routes.join("\n"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{answers, part_1, part_2};
+
+    const EXAMPLE: &str = "\
+London to Dublin = 464
+London to Belfast = 518
+Dublin to Belfast = 141";
+
+    #[test]
+    fn shortest_route() {
+        assert_eq!(part_1(&Day09, EXAMPLE), Found::Answer(605));
+    }
+
+    #[test]
+    fn longest_route() {
+        assert_eq!(part_2(&Day09, EXAMPLE), Found::Answer(982));
+    }
+
+    #[test]
+    fn disconnected_cities() {
+        let islands = "London to Dublin = 464\nBelfast to Paris = 100";
+        assert_eq!(
+            part_1(&Day09, islands).to_string(),
+            "none (no route visits every location)"
+        );
+    }
+
+    #[test]
+    fn too_many_cities() {
+        let chain: Vec<String> = (0..MAX_NODES)
+            .map(|city| format!("City{city} to City{} = 1", city + 1))
+            .collect();
+        assert_eq!(
+            part_1(&Day09, &chain.join("\n")).to_string(),
+            "none (too many locations to search every route)"
+        );
+    }
+
+    #[test]
+    fn answers_match() {
+        assert_eq!(answers(&Day09, 9), ["141", "736"]);
+    }
+}
@@ -0,0 +1,192 @@
+use crate::{input::PuzzleInput, parse::parse_number, random::Rng, Error, Solution};
+
+/// A sequence as runs of the same digit, each a count and the digit.
+fn runs(string: &str) -> Vec<(usize, char)> {
+    let mut runs: Vec<(usize, char)> = Vec::new();
+    for c in string.chars() {
+        match runs.last_mut() {
+            Some((count, digit)) if *digit == c => *count += 1,
+            _ => runs.push((1, c)),
+        }
+    }
+    runs
+}
+
+/// One look-and-say step on runs rather than digits. Runs are what the step
+/// reads off anyway, and there are far fewer of them.
+fn look_say_runs(runs: &[(usize, char)]) -> Vec<(usize, char)> {
+    let mut next: Vec<(usize, char)> = Vec::with_capacity(runs.len() * 2);
+    for &(count, digit) in runs {
+        for c in count.to_string().chars().chain([digit]) {
+            match next.last_mut() {
+                Some((count, digit)) if *digit == c => *count += 1,
+                _ => next.push((1, c)),
+            }
+        }
+    }
+    next
+}
+
+fn look_say_length(input: &str, steps: usize) -> usize {
+    let runs = (0..steps).fold(runs(input), |runs, _| look_say_runs(&runs));
+    runs.iter().map(|&(count, _)| count).sum()
+}
+
+#[cfg(test)]
+fn look_say(string: &str) -> String {
+    if string.is_empty() {
+        return String::from("");
+    }
+    let mut new_string = String::with_capacity(string.len() * 2);
+    let mut chars = string.chars();
+    let mut prev_char = chars.next().unwrap();
+    let mut count: u32 = 1;
+
+    for c in chars {
+        if prev_char == c {
+            count += 1;
+        } else {
+            new_string.push_str(&format!("{count}{prev_char}"));
+            prev_char = c;
+            count = 1;
+        }
+    }
+
+    new_string.push_str(&format!("{count}{prev_char}"));
+
+    new_string
+}
+
+/// How many look-and-say steps each part takes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Config {
+    pub part_1_steps: usize,
+    pub part_2_steps: usize,
+}
+
+impl Config {
+    pub const PUZZLE: Config = Config {
+        part_1_steps: 40,
+        part_2_steps: 50,
+    };
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config::PUZZLE
+    }
+}
+
+#[derive(Clone, Default)]
+pub struct Day10 {
+    pub config: Config,
+}
+
+impl Day10 {
+    pub const PUZZLE: Day10 = Day10 {
+        config: Config::PUZZLE,
+    };
+}
+
+impl Solution for Day10 {
+    type Input<'a> = &'a str;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    const PUZZLE_SIZE: usize = 10;
+    const PARAMETERS: &'static [&'static str] = &["part_1_steps", "part_2_steps"];
+
+    fn parse<'a>(&self, input: &'a PuzzleInput) -> Result<Self::Input<'a>, Error> {
+        let digits = input.trim();
+        if digits.is_empty() {
+            return Err(Error::new("Expected a sequence of digits"));
+        }
+        match digits.char_indices().find(|(_, c)| !c.is_ascii_digit()) {
+            Some((i, c)) => Err(Error::at(&digits[i..i + c.len_utf8()], "Expected a digit")),
+            None => Ok(digits),
+        }
+    }
+
+    fn part_1(&self, input: &&str) -> usize {
+        look_say_length(input, self.config.part_1_steps)
+    }
+
+    fn part_2(&self, input: &&str) -> usize {
+        look_say_length(input, self.config.part_2_steps)
+    }
+
+    /// A starting sequence of `size` digits from 1 to 3.
+    fn generate(&self, rng: &mut Rng, size: usize) -> PuzzleInput {
+        PuzzleInput::new(
+            (0..size)
+                .map(|_| char::from(b'0' + rng.range(1..=3)))
+                .collect::<String>(),
+        )
+    }
+
+    fn configure(&mut self, name: &str, value: &str) -> Result<(), Error> {
+        let steps = parse_number(value, "number of steps")?;
+        match name {
+            "part_1_steps" => self.config.part_1_steps = steps,
+            _ => self.config.part_2_steps = steps,
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        check::Checker,
+        testing::{answers, part_1, part_2},
+    };
+
+    #[test]
+    fn look_and_say_sequence() {
+        let sequence = ["1", "11", "21", "1211", "111221", "312211"];
+        for step in sequence.windows(2) {
+            assert_eq!(look_say(step[0]), step[1]);
+        }
+        assert_eq!(look_say(""), "");
+    }
+
+    #[test]
+    fn rejects_other_characters() {
+        let error = Day10::PUZZLE
+            .parse(&PuzzleInput::new("1321a1\n"))
+            .unwrap_err();
+        assert_eq!(error.to_string(), "Expected a digit: \"a\"");
+    }
+
+    #[test]
+    fn configured_steps() {
+        let day = Day10 {
+            config: Config {
+                part_1_steps: 5,
+                part_2_steps: 1,
+            },
+        };
+        assert_eq!(part_1(&day, "1"), "312211".len());
+        assert_eq!(part_2(&day, "1"), "11".len());
+    }
+
+    #[test]
+    fn runs_match_strings() {
+        Checker::new(&Day10::PUZZLE)
+            .differential(
+                |input| {
+                    (0..20)
+                        .fold(input.to_string(), |string, _| look_say(&string))
+                        .len()
+                },
+                |input| look_say_length(input, 20),
+            )
+            .unwrap();
+    }
+
+    #[test]
+    fn answers_match() {
+        assert_eq!(answers(&Day10::PUZZLE, 10), ["329356", "4666278"]);
+    }
+}
@@ -0,0 +1,149 @@
+use crate::{input::PuzzleInput, random::Rng, Error, Solution};
+
+#[derive(Clone)]
+pub struct ValidPassword {
+    bytes: [u8; 8],
+}
+
+impl ValidPassword {
+    fn new(current_password: &str) -> Result<Self, Error> {
+        if let Some((i, c)) = current_password
+            .char_indices()
+            .find(|(_, c)| !c.is_ascii_lowercase())
+        {
+            return Err(Error::at(
+                &current_password[i..i + c.len_utf8()],
+                "Password must contain only lowercase letters",
+            ));
+        }
+
+        Ok(ValidPassword {
+            bytes: current_password.as_bytes().try_into().map_err(|_| {
+                Error::at(
+                    current_password,
+                    "Passwords have to be exactly 8 characters long",
+                )
+            })?,
+        })
+    }
+
+    fn next(&mut self) -> String {
+        loop {
+            self.increment();
+            if self.is_valid() {
+                return self.as_string();
+            }
+        }
+    }
+
+    fn as_string(&self) -> String {
+        self.bytes.iter().map(|b| *b as char).collect()
+    }
+
+    fn increment(&mut self) {
+        for i in (0..self.bytes.len()).rev() {
+            self.bytes[i] += 1;
+            if self.bytes[i] <= b'z' {
+                break;
+            } else {
+                self.bytes[i] = b'a';
+            }
+        }
+    }
+
+    fn is_valid(&self) -> bool {
+        self.contains_a_straight()
+            && !self.contains_forbidden_letters()
+            && self.contains_two_pairs()
+    }
+
+    fn contains_a_straight(&self) -> bool {
+        self.bytes
+            .windows(3)
+            .any(|w| w[0] + 1 == w[1] && w[1] + 1 == w[2])
+    }
+
+    fn contains_forbidden_letters(&self) -> bool {
+        self.bytes.contains(&b'i') || self.bytes.contains(&b'o') || self.bytes.contains(&b'l')
+    }
+
+    fn contains_two_pairs(&self) -> bool {
+        self.bytes
+            .windows(2)
+            .enumerate()
+            .filter(|(_, w)| w[0] == w[1])
+            .map(|(i, _)| i)
+            .collect::<Vec<_>>()
+            .windows(2)
+            .any(|w| w[1] - w[0] > 1)
+    }
+}
+
+#[derive(Clone)]
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input<'a> = ValidPassword;
+    type Output1 = String;
+    type Output2 = String;
+
+    const PUZZLE_SIZE: usize = 8;
+
+    fn parse<'a>(&self, input: &'a PuzzleInput) -> Result<Self::Input<'a>, Error> {
+        ValidPassword::new(input.trim())
+    }
+
+    fn part_1(&self, password: &ValidPassword) -> String {
+        password.clone().next()
+    }
+
+    fn part_2(&self, password: &ValidPassword) -> String {
+        let mut password = password.clone();
+        password.next();
+        password.next()
+    }
+
+    /// A random password. Passwords are always eight letters, so `size` is
+    /// ignored. None of the letters are `i`, `o` or `l`, as counting past one
+    /// of those would take the search through a long run of invalid passwords.
+    fn generate(&self, rng: &mut Rng, _size: usize) -> PuzzleInput {
+        const LETTERS: &[u8] = b"abcdefghjkmnpqrstuvwxyz";
+        PuzzleInput::new(
+            (0..8)
+                .map(|_| char::from(*rng.choose(LETTERS)))
+                .collect::<String>(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{answers, part_1};
+
+    #[test]
+    fn password_rules() {
+        let hijklmmn = ValidPassword::new("hijklmmn").unwrap();
+        assert!(hijklmmn.contains_a_straight());
+        assert!(hijklmmn.contains_forbidden_letters());
+
+        let abbceffg = ValidPassword::new("abbceffg").unwrap();
+        assert!(abbceffg.contains_two_pairs());
+        assert!(!abbceffg.contains_a_straight());
+
+        assert!(!ValidPassword::new("abbcegjk").unwrap().contains_two_pairs());
+        assert!(!ValidPassword::new("aaabcdef").unwrap().contains_two_pairs());
+        assert!(ValidPassword::new("abcdffaa").unwrap().is_valid());
+    }
+
+    #[test]
+    fn next_password() {
+        assert_eq!(part_1(&Day11, "abcdefgh"), "abcdffaa");
+        assert_eq!(part_1(&Day11, "ghijklmn"), "ghjaabcc");
+    }
+
+    #[test]
+    fn answers_match() {
+        assert_eq!(answers(&Day11, 11), ["vzbxxyzz", "vzcaabcc"]);
+    }
+}
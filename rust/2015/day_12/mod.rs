@@ -0,0 +1,156 @@
+use crate::{input::PuzzleInput, random::Rng, Error, Solution};
+use serde_json::{Number, Value};
+use std::iter;
+
+/// Every number in `json`, leaving out objects with a `"red"` value if
+/// `ignore_red` is set. [`Day12::parse`] checks they are all whole numbers.
+fn sum_numbers(json: &Value, ignore_red: bool) -> i128 {
+    match json {
+        Value::Number(number) => number.as_i64().expect("Numbers are checked when parsed") as i128,
+        Value::Array(values) => values.iter().map(|v| sum_numbers(v, ignore_red)).sum(),
+        Value::Object(obj) if ignore_red && obj.values().any(|v| v == "red") => 0,
+        Value::Object(obj) => obj.values().map(|v| sum_numbers(v, ignore_red)).sum(),
+        _ => 0,
+    }
+}
+
+/// The first number in `json` that isn't a whole number fitting in an i64.
+fn find_invalid_number(json: &Value) -> Option<&Number> {
+    match json {
+        Value::Number(number) => number.as_i64().is_none().then_some(number),
+        Value::Array(values) => values.iter().find_map(find_invalid_number),
+        Value::Object(obj) => obj.values().find_map(find_invalid_number),
+        _ => None,
+    }
+}
+
+/// An array of nested arrays and objects that takes values from `budget`
+/// until it runs out, with numbers and colours as the leaves. Some of the
+/// objects are bound to hold a `"red"`.
+fn generate_json(rng: &mut Rng, budget: &mut usize, depth: u32) -> Value {
+    const COLOURS: [&str; 6] = ["red", "orange", "yellow", "green", "blue", "violet"];
+
+    if depth > 0 && (depth >= 5 || rng.chance(3, 4)) {
+        *budget = budget.saturating_sub(1);
+        return if rng.chance(1, 2) {
+            Value::from(rng.range(-200..=200))
+        } else {
+            Value::from(*rng.choose(&COLOURS))
+        };
+    }
+
+    let is_object = depth > 0 && rng.chance(1, 2);
+    let length = if depth == 0 {
+        usize::MAX
+    } else {
+        rng.range(1..=8)
+    };
+    let children =
+        iter::from_fn(|| (*budget > 0).then(|| generate_json(rng, budget, depth + 1))).take(length);
+    if is_object {
+        Value::Object(
+            ('a'..='h')
+                .zip(children)
+                .map(|(key, value)| (key.to_string(), value))
+                .collect(),
+        )
+    } else {
+        Value::Array(children.collect())
+    }
+}
+
+#[derive(Clone)]
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input<'a> = Value;
+    type Output1 = i128;
+    type Output2 = i128;
+
+    const PUZZLE_SIZE: usize = 4_000;
+
+    fn parse<'a>(&self, input: &'a PuzzleInput) -> Result<Self::Input<'a>, Error> {
+        let json: Value = serde_json::from_str(input).map_err(|e| {
+            let message = e.to_string();
+            let message = message.split(" at line ").next().unwrap_or_default();
+            Error::at_position(e.line(), e.column(), format!("Invalid JSON: {message}"))
+        })?;
+        match find_invalid_number(&json) {
+            Some(number) => Err(Error::new(format!(
+                "Expected whole numbers that fit in 64 bits, found {number}"
+            ))),
+            None => Ok(json),
+        }
+    }
+
+    fn part_1(&self, json: &Value) -> i128 {
+        sum_numbers(json, false)
+    }
+
+    fn part_2(&self, json: &Value) -> i128 {
+        sum_numbers(json, true)
+    }
+
+    /// A document holding about `size` numbers and strings.
+    fn generate(&self, rng: &mut Rng, size: usize) -> PuzzleInput {
+        let mut budget = size;
+        PuzzleInput::new(generate_json(rng, &mut budget, 0).to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{answers, part_1, part_2};
+
+    #[test]
+    fn sum_of_all_numbers() {
+        for (input, sum) in [
+            ("[1,2,3]", 6),
+            (r#"{"a":2,"b":4}"#, 6),
+            ("[[[3]]]", 3),
+            (r#"{"a":{"b":4},"c":-1}"#, 3),
+            (r#"{"a":[-1,1]}"#, 0),
+            (r#"[-1,{"a":1}]"#, 0),
+            ("[]", 0),
+            ("{}", 0),
+        ] {
+            assert_eq!(part_1(&Day12, input), sum, "{input}");
+        }
+    }
+
+    #[test]
+    fn ignoring_red_objects() {
+        for (input, sum) in [
+            ("[1,2,3]", 6),
+            (r#"[1,{"c":"red","b":2},3]"#, 4),
+            (r#"{"d":"red","e":[1,2,3,4],"f":5}"#, 0),
+            (r#"[1,"red",5]"#, 6),
+        ] {
+            assert_eq!(part_2(&Day12, input), sum, "{input}");
+        }
+    }
+
+    #[test]
+    fn rejects_other_numbers() {
+        let error = |input| {
+            Day12
+                .parse(&PuzzleInput::new(input))
+                .unwrap_err()
+                .to_string()
+        };
+        assert_eq!(
+            error("[1.5, 2e3, -0.5]"),
+            "Expected whole numbers that fit in 64 bits, found 1.5"
+        );
+        assert_eq!(
+            error("[99999999999999999999999]"),
+            "Expected whole numbers that fit in 64 bits, found 1e23"
+        );
+    }
+
+    #[test]
+    fn answers_match() {
+        assert_eq!(answers(&Day12, 12), ["191164", "87842"]);
+    }
+}
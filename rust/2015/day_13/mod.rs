@@ -0,0 +1,136 @@
+use crate::{
+    graph::{Goal, Graph, MAX_NODES},
+    input::PuzzleInput,
+    parse::Pattern,
+    random::Rng,
+    Error, Found, Solution,
+};
+
+const SEATING: Pattern = Pattern::new(
+    "{person} would {gain or lose} {happiness} happiness units by sitting next to {neighbour}.",
+);
+
+type Happiness = i16;
+
+/// How much each person's happiness changes when sitting next to another.
+fn parse_happiness(input: &str) -> Result<Graph<'_, Happiness>, Error> {
+    let mut graph = Graph::new();
+    for line in input.lines() {
+        let (person, direction, amount, neighbour): (_, _, Happiness, _) = SEATING.parse(line)?;
+        let change = match direction {
+            "gain" => amount,
+            "lose" => -amount,
+            _ => return Err(Error::at(direction, "Expected gain or lose")),
+        };
+        graph.add_edge(person, neighbour, change);
+    }
+    Ok(graph)
+}
+
+/// The happiest round table. Both neighbours of a pair feel the change, so the
+/// seating is scored on the graph where each edge counts both directions.
+fn best_seating(happiness: &Graph<Happiness>) -> Found<Happiness> {
+    if !happiness.is_searchable() {
+        return Found::Nothing("too many people to search every seating");
+    }
+    Found::from_option(
+        happiness
+            .symmetrised()
+            .best_cycle(Goal::Max)
+            .map(|cycle| cycle.value),
+        "not everyone has feelings about everyone else",
+    )
+}
+
+#[derive(Clone)]
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Input<'a> = Graph<'a, Happiness>;
+    type Output1 = Found<Happiness>;
+    type Output2 = Found<Happiness>;
+
+    const PUZZLE_SIZE: usize = 8;
+
+    fn parse<'a>(&self, input: &'a PuzzleInput) -> Result<Self::Input<'a>, Error> {
+        parse_happiness(input)
+    }
+
+    fn part_1(&self, happiness: &Graph<Happiness>) -> Found<Happiness> {
+        best_seating(happiness)
+    }
+
+    fn part_2(&self, happiness: &Graph<Happiness>) -> Found<Happiness> {
+        let mut happiness = happiness.clone();
+        for person in happiness.names().to_vec() {
+            happiness.add_symmetric_edge(person, "Me", 0);
+        }
+        best_seating(&happiness)
+    }
+
+    /// How each of `size` people feels about sitting next to every other,
+    /// from losing to gaining 100 happiness. There are few enough that part 2
+    /// can still seat everyone once you join them.
+    fn generate(&self, rng: &mut Rng, size: usize) -> PuzzleInput {
+        let people = rng.names(size.min(MAX_NODES - 1));
+        let mut lines = Vec::new();
+        for person in &people {
+            for neighbour in people.iter().filter(|&neighbour| neighbour != person) {
+                let change: Happiness = rng.range(-100..=100);
+                let direction = if change < 0 { "lose" } else { "gain" };
+                lines.push(format!(
+                    "{person} would {direction} {} happiness units by sitting next to {neighbour}.",
+                    change.abs()
+                ));
+            }
+        }
+        PuzzleInput::new(lines.join("\n"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        testing::{answers, part_1},
+        DynSolution, Part,
+    };
+
+    const EXAMPLE: &str = "\
+Alice would gain 54 happiness units by sitting next to Bob.
+Alice would lose 79 happiness units by sitting next to Carol.
+Alice would lose 2 happiness units by sitting next to David.
+Bob would gain 83 happiness units by sitting next to Alice.
+Bob would lose 7 happiness units by sitting next to Carol.
+Bob would lose 63 happiness units by sitting next to David.
+Carol would lose 62 happiness units by sitting next to Alice.
+Carol would gain 60 happiness units by sitting next to Bob.
+Carol would gain 55 happiness units by sitting next to David.
+David would gain 46 happiness units by sitting next to Alice.
+David would lose 7 happiness units by sitting next to Bob.
+David would gain 41 happiness units by sitting next to Carol.";
+
+    #[test]
+    fn optimal_seating() {
+        assert_eq!(part_1(&Day13, EXAMPLE), Found::Answer(330));
+    }
+
+    #[test]
+    fn invalid_line() {
+        assert!(parse_happiness("Alice would frown at Bob.").is_err());
+    }
+
+    #[test]
+    fn generated_tables_fit_you_too() {
+        let input = Day13.generate_input(1, 100);
+        let answers = Day13.solve(&input, &Part::BOTH).unwrap();
+        assert!(answers
+            .iter()
+            .all(|(_, answer)| !answer.starts_with("none")));
+    }
+
+    #[test]
+    fn answers_match() {
+        assert_eq!(answers(&Day13, 13), ["733", "725"]);
+    }
+}
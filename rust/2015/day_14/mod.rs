@@ -0,0 +1,230 @@
+use crate::{
+    input::PuzzleInput,
+    parse::{parse_number, Pattern},
+    random::Rng,
+    Error, Solution,
+};
+use std::collections::HashMap;
+
+const REINDEER: Pattern = Pattern::new(
+    "{name} can fly {speed} km/s for {flying time} seconds, but then must rest for {resting time} seconds.",
+);
+
+pub struct Reindeer<'a> {
+    name: &'a str,
+    speed: u16,
+    flying_time: u16,
+    resting_time: u16,
+}
+
+impl<'a> Reindeer<'a> {
+    fn new((name, speed, flying_time, resting_time): (&'a str, u16, u16, u16)) -> Self {
+        Reindeer {
+            name,
+            speed,
+            flying_time,
+            resting_time,
+        }
+    }
+
+    fn from_str(input: &'a str) -> Result<Self, Error> {
+        let reindeer = Self::new(REINDEER.parse(input)?);
+        // Every reindeer has to go through its cycle of flying and resting
+        if reindeer.flying_time == 0 && reindeer.resting_time == 0 {
+            return Err(Error::at(
+                input,
+                "Flying and resting can't both take 0 seconds",
+            ));
+        }
+        Ok(reindeer)
+    }
+
+    /// Whole cycles take at least as long as their flying, so this can't
+    /// overflow even for the longest race and fastest reindeer.
+    fn flying_distance_after(&self, seconds: u32) -> u64 {
+        let speed = u64::from(self.speed);
+        let flying_time = u32::from(self.flying_time);
+        let cycle_time = flying_time + u32::from(self.resting_time);
+        speed * u64::from(flying_time) * u64::from(seconds / cycle_time)
+            + speed * u64::from(flying_time.min(seconds % cycle_time))
+    }
+}
+
+fn winning_points_after(reindeers: &[Reindeer], seconds: u32) -> u32 {
+    let mut scores = HashMap::new();
+    for second in 1..=seconds {
+        let max_distance = reindeers
+            .iter()
+            .map(|r| r.flying_distance_after(second))
+            .max()
+            .unwrap();
+        reindeers
+            .iter()
+            .filter(|r| r.flying_distance_after(second) == max_distance)
+            .for_each(|r| *scores.entry(r.name).or_insert(0) += 1);
+    }
+    scores.into_values().max().unwrap()
+}
+
+/// How long the race lasts; the example's runs for 1000 seconds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Config {
+    pub seconds: u32,
+}
+
+impl Config {
+    pub const PUZZLE: Config = Config { seconds: 2503 };
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config::PUZZLE
+    }
+}
+
+#[derive(Clone, Default)]
+pub struct Day14 {
+    pub config: Config,
+}
+
+impl Day14 {
+    pub const PUZZLE: Day14 = Day14 {
+        config: Config::PUZZLE,
+    };
+}
+
+impl Solution for Day14 {
+    type Input<'a> = Vec<Reindeer<'a>>;
+    type Output1 = u64;
+    type Output2 = u32;
+
+    const PUZZLE_SIZE: usize = 9;
+    const PARAMETERS: &'static [&'static str] = &["seconds"];
+
+    fn parse<'a>(&self, input: &'a PuzzleInput) -> Result<Self::Input<'a>, Error> {
+        let reindeers: Vec<_> = input
+            .lines()
+            .map(Reindeer::from_str)
+            .collect::<Result<_, _>>()?;
+        if reindeers.is_empty() {
+            return Err(Error::new("Expected at least one reindeer"));
+        }
+        Ok(reindeers)
+    }
+
+    fn part_1(&self, reindeers: &Vec<Reindeer>) -> u64 {
+        reindeers
+            .iter()
+            .map(|r| r.flying_distance_after(self.config.seconds))
+            .max()
+            .unwrap()
+    }
+
+    fn part_2(&self, reindeers: &Vec<Reindeer>) -> u32 {
+        winning_points_after(reindeers, self.config.seconds)
+    }
+
+    /// `size` reindeer, and at least one.
+    fn generate(&self, rng: &mut Rng, size: usize) -> PuzzleInput {
+        let reindeer: Vec<String> = rng
+            .names(size.max(1))
+            .into_iter()
+            .map(|name| {
+                format!(
+                    "{name} can fly {} km/s for {} seconds, but then must rest for {} seconds.",
+                    rng.range(1..=30),
+                    rng.range(1..=20),
+                    rng.range(40..=200)
+                )
+            })
+            .collect();
+        PuzzleInput::new(reindeer.join("\n"))
+    }
+
+    /// A race needs at least a second for anyone to score a point.
+    fn configure(&mut self, _name: &str, value: &str) -> Result<(), Error> {
+        let seconds = parse_number(value, "number of seconds")?;
+        if seconds == 0 {
+            return Err(Error::at(value, "The race must last at least 1 second"));
+        }
+        self.config.seconds = seconds;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{answers, part_1, part_2};
+
+    const EXAMPLE: &str = "\
+Comet can fly 14 km/s for 10 seconds, but then must rest for 127 seconds.
+Dancer can fly 16 km/s for 11 seconds, but then must rest for 162 seconds.";
+
+    #[test]
+    fn distance_after_1000_seconds() {
+        let reindeers: Vec<_> = EXAMPLE
+            .lines()
+            .map(|line| Reindeer::from_str(line).unwrap())
+            .collect();
+        assert_eq!(reindeers[0].flying_distance_after(1000), 1120);
+        assert_eq!(reindeers[1].flying_distance_after(1000), 1056);
+    }
+
+    #[test]
+    fn points_after_1000_seconds() {
+        let reindeers: Vec<_> = EXAMPLE
+            .lines()
+            .map(|line| Reindeer::from_str(line).unwrap())
+            .collect();
+        assert_eq!(winning_points_after(&reindeers, 1000), 689);
+    }
+
+    #[test]
+    fn example_race() {
+        let day = Day14 {
+            config: Config { seconds: 1000 },
+        };
+        assert_eq!(part_1(&day, EXAMPLE), 1120);
+        assert_eq!(part_2(&day, EXAMPLE), 689);
+    }
+
+    #[test]
+    fn long_races() {
+        let day = Day14 {
+            config: Config { seconds: 65535 },
+        };
+        assert_eq!(part_1(&day, EXAMPLE), 67060);
+        let reindeers: Vec<_> = EXAMPLE
+            .lines()
+            .map(|line| Reindeer::from_str(line).unwrap())
+            .collect();
+        assert_eq!(reindeers[0].flying_distance_after(u32::MAX), 4389017780);
+    }
+
+    #[test]
+    fn rejects_empty_cycles() {
+        let error = Reindeer::from_str(
+            "Dasher can fly 3 km/s for 0 seconds, but then must rest for 0 seconds.",
+        )
+        .err()
+        .unwrap();
+        assert_eq!(
+            error.message(),
+            "Flying and resting can't both take 0 seconds"
+        );
+    }
+
+    #[test]
+    fn rejects_races_without_reindeer_or_time() {
+        let error = Day14::PUZZLE.parse(&PuzzleInput::new("")).err().unwrap();
+        assert_eq!(error.message(), "Expected at least one reindeer");
+        let error = Day14::default().configure("seconds", "0").unwrap_err();
+        assert_eq!(error.message(), "The race must last at least 1 second");
+    }
+
+    #[test]
+    fn answers_match() {
+        assert_eq!(answers(&Day14::PUZZLE, 14), ["2655", "1059"]);
+    }
+}
@@ -0,0 +1,185 @@
+use crate::{
+    combinatorics::compositions,
+    input::PuzzleInput,
+    parse::{key_values, parse_number, Pattern},
+    random::Rng,
+    Error, Solution,
+};
+
+// First, let's create a struct to represent an ingredient
+pub struct Ingredient {
+    capacity: i32,
+    durability: i32,
+    flavor: i32,
+    texture: i32,
+    calories: i32,
+}
+
+const PROPERTIES: [&str; 5] = ["capacity", "durability", "flavor", "texture", "calories"];
+
+const INGREDIENT: Pattern = Pattern::new("{name}: {properties}");
+
+impl Ingredient {
+    fn from_str(line: &str) -> Result<Self, Error> {
+        let (_, properties): (&str, _) = INGREDIENT.parse(line)?;
+        let properties = key_values(properties, ", ", " ")?.restrict_to(&PROPERTIES)?;
+        let [capacity, durability, flavor, texture, calories] =
+            PROPERTIES.map(|name| properties.require(name));
+        Ok(Self {
+            capacity: capacity?,
+            durability: durability?,
+            flavor: flavor?,
+            texture: texture?,
+            calories: calories?,
+        })
+    }
+}
+
+fn calculate_score(ingredients: &[Ingredient], amounts: &[usize]) -> i64 {
+    let mut capacity = 0;
+    let mut durability = 0;
+    let mut flavor = 0;
+    let mut texture = 0;
+
+    for (ingredient, &amount) in ingredients.iter().zip(amounts.iter()) {
+        let amount = amount as i32;
+        capacity += ingredient.capacity * amount;
+        durability += ingredient.durability * amount;
+        flavor += ingredient.flavor * amount;
+        texture += ingredient.texture * amount;
+    }
+
+    capacity = capacity.max(0);
+    durability = durability.max(0);
+    flavor = flavor.max(0);
+    texture = texture.max(0);
+
+    capacity as i64 * durability as i64 * flavor as i64 * texture as i64
+}
+
+fn calculate_calories(ingredients: &[Ingredient], amounts: &[usize]) -> i32 {
+    ingredients
+        .iter()
+        .zip(amounts.iter())
+        .map(|(i, &a)| i.calories * a as i32)
+        .sum()
+}
+
+/// The best score over every way of sharing the teaspoons between the
+/// ingredients that `allowed` accepts.
+fn best_score(
+    ingredients: &[Ingredient],
+    teaspoons: usize,
+    allowed: impl Fn(&[usize]) -> bool,
+) -> i64 {
+    compositions(teaspoons, ingredients.len())
+        .filter(|amounts| allowed(amounts))
+        .map(|amounts| calculate_score(ingredients, &amounts))
+        .max()
+        .unwrap_or(0)
+}
+
+/// How many teaspoons make a cookie, and how many calories part 2's must have.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Config {
+    pub teaspoons: usize,
+    pub calories: i32,
+}
+
+impl Config {
+    pub const PUZZLE: Config = Config {
+        teaspoons: 100,
+        calories: 500,
+    };
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config::PUZZLE
+    }
+}
+
+#[derive(Clone, Default)]
+pub struct Day15 {
+    pub config: Config,
+}
+
+impl Day15 {
+    pub const PUZZLE: Day15 = Day15 {
+        config: Config::PUZZLE,
+    };
+}
+
+impl Solution for Day15 {
+    type Input<'a> = Vec<Ingredient>;
+    type Output1 = i64;
+    type Output2 = i64;
+
+    const PUZZLE_SIZE: usize = 4;
+    const PARAMETERS: &'static [&'static str] = &["teaspoons", "calories"];
+
+    fn parse<'a>(&self, input: &'a PuzzleInput) -> Result<Self::Input<'a>, Error> {
+        input.lines().map(Ingredient::from_str).collect()
+    }
+
+    fn part_1(&self, ingredients: &Vec<Ingredient>) -> i64 {
+        best_score(ingredients, self.config.teaspoons, |_| true)
+    }
+
+    fn part_2(&self, ingredients: &Vec<Ingredient>) -> i64 {
+        best_score(ingredients, self.config.teaspoons, |amounts| {
+            calculate_calories(ingredients, amounts) == self.config.calories
+        })
+    }
+
+    /// `size` ingredients with properties from -5 to 5. Every way of sharing
+    /// the teaspoons gets scored, so the work grows steeply with `size`.
+    fn generate(&self, rng: &mut Rng, size: usize) -> PuzzleInput {
+        let ingredients: Vec<String> = rng
+            .names(size)
+            .into_iter()
+            .map(|name| {
+                let [capacity, durability, flavor, texture] = [(); 4].map(|_| rng.range(-5..=5));
+                let calories = rng.range(1..=8);
+                format!(
+                    "{name}: capacity {capacity}, durability {durability}, flavor {flavor}, \
+                     texture {texture}, calories {calories}"
+                )
+            })
+            .collect();
+        PuzzleInput::new(ingredients.join("\n"))
+    }
+
+    fn configure(&mut self, name: &str, value: &str) -> Result<(), Error> {
+        match name {
+            "teaspoons" => self.config.teaspoons = parse_number(value, "number of teaspoons")?,
+            _ => self.config.calories = parse_number(value, "number of calories")?,
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{answers, part_1, part_2};
+
+    const EXAMPLE: &str = "\
+Butterscotch: capacity -1, durability -2, flavor 6, texture 3, calories 8
+Cinnamon: capacity 2, durability 3, flavor -2, texture -1, calories 3";
+
+    #[test]
+    fn best_cookie() {
+        assert_eq!(part_1(&Day15::PUZZLE, EXAMPLE), 62842880);
+    }
+
+    #[test]
+    fn best_500_calorie_cookie() {
+        assert_eq!(part_2(&Day15::PUZZLE, EXAMPLE), 57600000);
+    }
+
+    #[test]
+    fn answers_match() {
+        assert_eq!(answers(&Day15::PUZZLE, 15), ["18965440", "15862900"]);
+    }
+}
@@ -0,0 +1,433 @@
+use crate::{
+    input::PuzzleInput,
+    parse::{key_values, parse_number, Pattern},
+    random::Rng,
+    Error, Found, Solution,
+};
+
+const SUE: Pattern = Pattern::new("Sue {number}: {properties}");
+
+const PROPERTIES: [&str; 10] = [
+    "children",
+    "cats",
+    "samoyeds",
+    "pomeranians",
+    "akitas",
+    "vizslas",
+    "goldfish",
+    "trees",
+    "cars",
+    "perfumes",
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SueProperties {
+    children: Option<u8>,
+    cats: Option<u8>,
+    samoyeds: Option<u8>,
+    pomeranians: Option<u8>,
+    akitas: Option<u8>,
+    vizslas: Option<u8>,
+    goldfish: Option<u8>,
+    trees: Option<u8>,
+    cars: Option<u8>,
+    perfumes: Option<u8>,
+}
+
+impl SueProperties {
+    fn from_str(line: &str) -> Result<Self, Error> {
+        let (_, properties): (u16, _) = SUE.parse(line)?;
+        let properties = key_values(properties, ", ", ": ")?.restrict_to(&PROPERTIES)?;
+
+        Ok(Self {
+            children: properties.get("children"),
+            cats: properties.get("cats"),
+            samoyeds: properties.get("samoyeds"),
+            pomeranians: properties.get("pomeranians"),
+            akitas: properties.get("akitas"),
+            vizslas: properties.get("vizslas"),
+            goldfish: properties.get("goldfish"),
+            trees: properties.get("trees"),
+            cars: properties.get("cars"),
+            perfumes: properties.get("perfumes"),
+        })
+    }
+
+    fn get(&self, property: &str) -> Option<u8> {
+        match property {
+            "children" => self.children,
+            "cats" => self.cats,
+            "samoyeds" => self.samoyeds,
+            "pomeranians" => self.pomeranians,
+            "akitas" => self.akitas,
+            "vizslas" => self.vizslas,
+            "goldfish" => self.goldfish,
+            "trees" => self.trees,
+            "cars" => self.cars,
+            "perfumes" => self.perfumes,
+            _ => None,
+        }
+    }
+
+    fn set(&mut self, property: &str, value: u8) {
+        let field = match property {
+            "children" => &mut self.children,
+            "cats" => &mut self.cats,
+            "samoyeds" => &mut self.samoyeds,
+            "pomeranians" => &mut self.pomeranians,
+            "akitas" => &mut self.akitas,
+            "vizslas" => &mut self.vizslas,
+            "goldfish" => &mut self.goldfish,
+            "trees" => &mut self.trees,
+            "cars" => &mut self.cars,
+            "perfumes" => &mut self.perfumes,
+            _ => return,
+        };
+        *field = Some(value);
+    }
+
+    fn matches_part_1(&self, mfcsam: &SueProperties) -> bool {
+        if let Some(children) = self.children {
+            if children != mfcsam.children.unwrap() {
+                return false;
+            }
+        }
+
+        if let Some(cats) = self.cats {
+            if cats != mfcsam.cats.unwrap() {
+                return false;
+            }
+        }
+
+        if let Some(samoyeds) = self.samoyeds {
+            if samoyeds != mfcsam.samoyeds.unwrap() {
+                return false;
+            }
+        }
+
+        if let Some(pomeranians) = self.pomeranians {
+            if pomeranians != mfcsam.pomeranians.unwrap() {
+                return false;
+            }
+        }
+
+        if let Some(akitas) = self.akitas {
+            if akitas != mfcsam.akitas.unwrap() {
+                return false;
+            }
+        }
+
+        if let Some(vizslas) = self.vizslas {
+            if vizslas != mfcsam.vizslas.unwrap() {
+                return false;
+            }
+        }
+
+        if let Some(goldfish) = self.goldfish {
+            if goldfish != mfcsam.goldfish.unwrap() {
+                return false;
+            }
+        }
+
+        if let Some(trees) = self.trees {
+            if trees != mfcsam.trees.unwrap() {
+                return false;
+            }
+        }
+
+        if let Some(cars) = self.cars {
+            if cars != mfcsam.cars.unwrap() {
+                return false;
+            }
+        }
+
+        if let Some(perfumes) = self.perfumes {
+            if perfumes != mfcsam.perfumes.unwrap() {
+                return false;
+            }
+        }
+
+        true
+    }
+
+    fn matches_part_2(&self, mfcsam: &SueProperties) -> bool {
+        if let Some(children) = self.children {
+            if children != mfcsam.children.unwrap() {
+                return false;
+            }
+        }
+
+        if let Some(cats) = self.cats {
+            if cats <= mfcsam.cats.unwrap() {
+                return false;
+            }
+        }
+
+        if let Some(samoyeds) = self.samoyeds {
+            if samoyeds != mfcsam.samoyeds.unwrap() {
+                return false;
+            }
+        }
+
+        if let Some(pomeranians) = self.pomeranians {
+            if pomeranians >= mfcsam.pomeranians.unwrap() {
+                return false;
+            }
+        }
+
+        if let Some(akitas) = self.akitas {
+            if akitas != mfcsam.akitas.unwrap() {
+                return false;
+            }
+        }
+
+        if let Some(vizslas) = self.vizslas {
+            if vizslas != mfcsam.vizslas.unwrap() {
+                return false;
+            }
+        }
+
+        if let Some(goldfish) = self.goldfish {
+            if goldfish >= mfcsam.goldfish.unwrap() {
+                return false;
+            }
+        }
+
+        if let Some(trees) = self.trees {
+            if trees <= mfcsam.trees.unwrap() {
+                return false;
+            }
+        }
+
+        if let Some(cars) = self.cars {
+            if cars != mfcsam.cars.unwrap() {
+                return false;
+            }
+        }
+
+        if let Some(perfumes) = self.perfumes {
+            if perfumes != mfcsam.perfumes.unwrap() {
+                return false;
+            }
+        }
+
+        true
+    }
+}
+
+const MFCSAM: SueProperties = SueProperties {
+    children: Some(3),
+    cats: Some(7),
+    samoyeds: Some(2),
+    pomeranians: Some(3),
+    akitas: Some(0),
+    vizslas: Some(0),
+    goldfish: Some(5),
+    trees: Some(3),
+    cars: Some(2),
+    perfumes: Some(1),
+};
+
+fn find_sue<F>(sues: &[SueProperties], matches: F) -> Found<usize>
+where
+    F: Fn(&SueProperties) -> bool,
+{
+    Found::from_option(
+        sues.iter().position(matches).map(|i| i + 1),
+        "no Sue matches the readings",
+    )
+}
+
+/// `size` Sues remembering three things each. Every random Sue has one exact
+/// reading wrong, and two planted ones match: one by each part's rules. No Sue
+/// can match part 2's if there are no fewer pomeranians than the reading or
+/// no more cats or trees, so then that one is left out.
+fn generate_sues(rng: &mut Rng, size: usize, mfcsam: &SueProperties) -> String {
+    const EXACT: [&str; 6] = [
+        "children", "samoyeds", "akitas", "vizslas", "cars", "perfumes",
+    ];
+    let reading = |property| mfcsam.get(property).unwrap();
+
+    let mut sues: Vec<Vec<(&str, u8)>> = (0..size.saturating_sub(2))
+        .map(|_| {
+            let wrong = *rng.choose(&EXACT);
+            let mut others = PROPERTIES.to_vec();
+            others.retain(|&property| property != wrong);
+            rng.shuffle(&mut others);
+            // At most 10, so never the reading when that is larger
+            let wrong_value = (u16::from(reading(wrong)) + rng.range(1..=10)) % 11;
+            let mut remembered = vec![(wrong, wrong_value as u8)];
+            remembered.extend(
+                others[..2]
+                    .iter()
+                    .map(|&property| (property, rng.range(0..=10))),
+            );
+            rng.shuffle(&mut remembered);
+            remembered
+        })
+        .collect();
+
+    let mut exact = EXACT.to_vec();
+    rng.shuffle(&mut exact);
+    let part_1 = vec![
+        ("cats", reading("cats")),
+        (exact[0], reading(exact[0])),
+        (exact[1], reading(exact[1])),
+    ];
+    let more = |rng: &mut Rng, property| {
+        let reading = reading(property);
+        (reading < u8::MAX).then(|| rng.range(reading + 1..=reading.saturating_add(3)))
+    };
+    let pomeranians = reading("pomeranians");
+    let part_2: Option<Vec<(&str, u8)>> = [
+        ("cats", more(rng, "cats")),
+        ("trees", more(rng, "trees")),
+        (
+            "pomeranians",
+            (pomeranians > 0).then(|| rng.range(0..=pomeranians - 1)),
+        ),
+    ]
+    .into_iter()
+    .map(|(property, value)| Some((property, value?)))
+    .collect();
+    for planted in [Some(part_1), part_2].into_iter().flatten() {
+        let position = rng.range(0..=sues.len());
+        sues.insert(position, planted);
+    }
+
+    let lines: Vec<String> = sues
+        .iter()
+        .enumerate()
+        .map(|(i, remembered)| {
+            let remembered: Vec<String> = remembered
+                .iter()
+                .map(|(property, value)| format!("{property}: {value}"))
+                .collect();
+            format!("Sue {}: {}", i + 1, remembered.join(", "))
+        })
+        .collect();
+    lines.join("\n")
+}
+
+/// What the MFCSAM read from the gift; each reading is a parameter of its own.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Config {
+    pub mfcsam: SueProperties,
+}
+
+impl Config {
+    pub const PUZZLE: Config = Config { mfcsam: MFCSAM };
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config::PUZZLE
+    }
+}
+
+#[derive(Clone, Default)]
+pub struct Day16 {
+    pub config: Config,
+}
+
+impl Day16 {
+    pub const PUZZLE: Day16 = Day16 {
+        config: Config::PUZZLE,
+    };
+}
+
+impl Solution for Day16 {
+    type Input<'a> = Vec<SueProperties>;
+    type Output1 = Found<usize>;
+    type Output2 = Found<usize>;
+
+    const PUZZLE_SIZE: usize = 500;
+    const PARAMETERS: &'static [&'static str] = &PROPERTIES;
+
+    fn parse<'a>(&self, input: &'a PuzzleInput) -> Result<Self::Input<'a>, Error> {
+        input.lines().map(SueProperties::from_str).collect()
+    }
+
+    fn part_1(&self, sues: &Vec<SueProperties>) -> Found<usize> {
+        find_sue(sues, |sue| sue.matches_part_1(&self.config.mfcsam))
+    }
+
+    fn part_2(&self, sues: &Vec<SueProperties>) -> Found<usize> {
+        find_sue(sues, |sue| sue.matches_part_2(&self.config.mfcsam))
+    }
+
+    fn generate(&self, rng: &mut Rng, size: usize) -> PuzzleInput {
+        PuzzleInput::new(generate_sues(rng, size, &self.config.mfcsam))
+    }
+
+    fn configure(&mut self, name: &str, value: &str) -> Result<(), Error> {
+        let reading = parse_number(value, "reading")?;
+        self.config.mfcsam.set(name, reading);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{testing::answers, DynSolution, Part};
+
+    fn sue(line: &str) -> SueProperties {
+        SueProperties::from_str(line).unwrap()
+    }
+
+    #[test]
+    fn exact_readings() {
+        assert!(sue("Sue 1: cats: 7, trees: 3, cars: 2").matches_part_1(&MFCSAM));
+        assert!(!sue("Sue 2: cats: 8, trees: 3").matches_part_1(&MFCSAM));
+    }
+
+    #[test]
+    fn ranged_readings() {
+        let sue_3 = sue("Sue 3: cats: 8, trees: 4, goldfish: 4, pomeranians: 2");
+        assert!(sue_3.matches_part_2(&MFCSAM));
+        assert!(!sue_3.matches_part_1(&MFCSAM));
+        assert!(!sue("Sue 4: cats: 7").matches_part_2(&MFCSAM));
+        assert!(!sue("Sue 5: goldfish: 5").matches_part_2(&MFCSAM));
+    }
+
+    #[test]
+    fn generated_sues_match_once_for_each_part() {
+        for seed in 0..5 {
+            let input = Day16::PUZZLE.generate(&mut Rng::new(seed), 100);
+            let sues = Day16::PUZZLE.parse(&input).unwrap();
+            assert_eq!(sues.len(), 100);
+            let matches = |rule: fn(&SueProperties, &SueProperties) -> bool| {
+                sues.iter().filter(|sue| rule(sue, &MFCSAM)).count()
+            };
+            assert_eq!(matches(SueProperties::matches_part_1), 1);
+            assert_eq!(matches(SueProperties::matches_part_2), 1);
+        }
+    }
+
+    #[test]
+    fn generated_sues_for_extreme_readings() {
+        let parameters = [("pomeranians", "0"), ("cats", "255"), ("children", "250")]
+            .map(|(name, value)| (name.to_string(), value.to_string()));
+        let day = Day16::PUZZLE.configured(&parameters).unwrap();
+        let input = day.generate_input(1, 50);
+        let answers = day.solve(&input, &Part::BOTH).unwrap();
+        assert!(!answers[0].1.starts_with("none"));
+        assert_eq!(answers[1].1, "none (no Sue matches the readings)");
+    }
+
+    #[test]
+    fn no_matching_sue() {
+        let input = PuzzleInput::new("Sue 1: cats: 0");
+        let sues = Day16::PUZZLE.parse(&input).unwrap();
+        assert_eq!(
+            Day16::PUZZLE.part_1(&sues).to_string(),
+            "none (no Sue matches the readings)"
+        );
+    }
+
+    #[test]
+    fn answers_match() {
+        assert_eq!(answers(&Day16::PUZZLE, 16), ["103", "405"]);
+    }
+}
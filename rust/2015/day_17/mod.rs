@@ -0,0 +1,107 @@
+use crate::{
+    combinatorics::{count_subsets_with_sum, count_subsets_with_sum_by_size},
+    input::PuzzleInput,
+    parse::parse_number,
+    random::Rng,
+    Error, Found, Solution,
+};
+
+const TOO_MANY: &str = "there are more ways than fit in a u64";
+
+/// How much eggnog the containers have to hold.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Config {
+    pub litres: usize,
+}
+
+impl Config {
+    pub const PUZZLE: Config = Config { litres: 150 };
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config::PUZZLE
+    }
+}
+
+#[derive(Clone, Default)]
+pub struct Day17 {
+    pub config: Config,
+}
+
+impl Day17 {
+    pub const PUZZLE: Day17 = Day17 {
+        config: Config::PUZZLE,
+    };
+}
+
+impl Solution for Day17 {
+    type Input<'a> = Vec<usize>;
+    type Output1 = Found<u64>;
+    type Output2 = Found<u64>;
+
+    const PUZZLE_SIZE: usize = 20;
+    const PARAMETERS: &'static [&'static str] = &["litres"];
+
+    fn parse<'a>(&self, input: &'a PuzzleInput) -> Result<Self::Input<'a>, Error> {
+        input
+            .lines()
+            .map(|container| parse_number(container, "container size"))
+            .collect()
+    }
+
+    fn part_1(&self, containers: &Vec<usize>) -> Found<u64> {
+        Found::from_option(
+            count_subsets_with_sum(containers, self.config.litres),
+            TOO_MANY,
+        )
+    }
+
+    fn part_2(&self, containers: &Vec<usize>) -> Found<u64> {
+        // Indexed by the number of containers, so the first non-zero count is
+        // for the fewest containers
+        let fewest = count_subsets_with_sum_by_size(containers, self.config.litres)
+            .into_iter()
+            .find(|&count| count != Some(0))
+            .unwrap_or(Some(0));
+        Found::from_option(fewest, TOO_MANY)
+    }
+
+    /// `size` containers of 5 to 50 litres.
+    fn generate(&self, rng: &mut Rng, size: usize) -> PuzzleInput {
+        let containers: Vec<String> = (0..size).map(|_| rng.range(5..=50).to_string()).collect();
+        PuzzleInput::new(containers.join("\n"))
+    }
+
+    fn configure(&mut self, _name: &str, value: &str) -> Result<(), Error> {
+        self.config.litres = parse_number(value, "number of litres")?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{answers, part_1, part_2};
+
+    #[test]
+    fn example_containers() {
+        let by_size = count_subsets_with_sum_by_size(&[20, 15, 10, 5, 5], 25);
+        assert_eq!(by_size.iter().flatten().sum::<u64>(), 4);
+        assert_eq!(by_size[2], Some(3));
+    }
+
+    #[test]
+    fn example_with_25_litres() {
+        let day = Day17 {
+            config: Config { litres: 25 },
+        };
+        assert_eq!(part_1(&day, "20\n15\n10\n5\n5"), Found::Answer(4));
+        assert_eq!(part_2(&day, "20\n15\n10\n5\n5"), Found::Answer(3));
+    }
+
+    #[test]
+    fn answers_match() {
+        assert_eq!(answers(&Day17::PUZZLE, 17), ["1304", "18"]);
+    }
+}
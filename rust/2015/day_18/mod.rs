@@ -0,0 +1,188 @@
+use crate::{
+    grid::{Grid, Neighbourhood, Point},
+    input::PuzzleInput,
+    parse::parse_number,
+    random::Rng,
+    Error, Solution,
+};
+
+#[derive(Clone)]
+pub struct LightGrid {
+    grid: Grid<bool>,
+    pinned: Vec<Point>,
+}
+
+impl LightGrid {
+    fn new(input: &str) -> Result<Self, Error> {
+        let grid = Grid::parse_lights(input)?;
+        if grid.width() == 0 || grid.height() == 0 {
+            return Err(Error::new("Expected at least one light"));
+        }
+
+        Ok(LightGrid {
+            grid,
+            pinned: Vec::new(),
+        })
+    }
+
+    fn pin(&mut self, x: i32, y: i32) {
+        let light = Point::new(x, y);
+        self.pinned.push(light);
+        self.grid[light] = true;
+    }
+
+    fn should_be_on(&self, light: Point) -> bool {
+        if self.pinned.contains(&light) {
+            return true;
+        }
+
+        let on_neighbors = self
+            .grid
+            .neighbours(light, Neighbourhood::Eight)
+            .filter(|&neighbor| self.grid[neighbor])
+            .count();
+        on_neighbors == 3 || self.grid[light] && on_neighbors == 2
+    }
+
+    fn step(&mut self) {
+        self.grid = self.grid.map(|light, _| self.should_be_on(light));
+    }
+
+    fn step_n(&mut self, n: usize) {
+        for _ in 0..n {
+            self.step();
+        }
+    }
+
+    fn count_on(&self) -> usize {
+        self.grid.count_on()
+    }
+}
+
+/// How many steps the animation runs for; the example's runs for 4 or 5.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Config {
+    pub steps: usize,
+}
+
+impl Config {
+    pub const PUZZLE: Config = Config { steps: 100 };
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config::PUZZLE
+    }
+}
+
+#[derive(Clone, Default)]
+pub struct Day18 {
+    pub config: Config,
+}
+
+impl Day18 {
+    pub const PUZZLE: Day18 = Day18 {
+        config: Config::PUZZLE,
+    };
+}
+
+impl Solution for Day18 {
+    type Input<'a> = LightGrid;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    const PUZZLE_SIZE: usize = 100;
+    const PARAMETERS: &'static [&'static str] = &["steps"];
+
+    fn parse<'a>(&self, input: &'a PuzzleInput) -> Result<Self::Input<'a>, Error> {
+        LightGrid::new(input)
+    }
+
+    fn part_1(&self, grid: &LightGrid) -> usize {
+        let mut grid = grid.clone();
+        grid.step_n(self.config.steps);
+        grid.count_on()
+    }
+
+    fn part_2(&self, grid: &LightGrid) -> usize {
+        let mut grid = grid.clone();
+        let right = grid.grid.width() as i32 - 1;
+        let bottom = grid.grid.height() as i32 - 1;
+        grid.pin(0, 0);
+        grid.pin(0, bottom);
+        grid.pin(right, 0);
+        grid.pin(right, bottom);
+        grid.step_n(self.config.steps);
+        grid.count_on()
+    }
+
+    /// A `size` by `size` grid with about half of the lights on.
+    fn generate(&self, rng: &mut Rng, size: usize) -> PuzzleInput {
+        let grid = Grid::new(size, size, false).map(|_, _| rng.chance(1, 2));
+        PuzzleInput::new(grid.to_string())
+    }
+
+    fn configure(&mut self, _name: &str, value: &str) -> Result<(), Error> {
+        self.config.steps = parse_number(value, "number of steps")?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{answers, part_1, part_2};
+
+    fn grid_with(lights: &[(i32, i32)]) -> LightGrid {
+        let mut grid = Grid::new(Day18::PUZZLE_SIZE, Day18::PUZZLE_SIZE, false);
+        for &(x, y) in lights {
+            grid[Point::new(x, y)] = true;
+        }
+        LightGrid::new(&grid.to_string()).unwrap()
+    }
+
+    fn lit(grid: &LightGrid, lights: &[(i32, i32)]) -> bool {
+        lights.iter().all(|&(x, y)| grid.grid[Point::new(x, y)])
+    }
+
+    #[test]
+    fn blinker_oscillates() {
+        let mut grid = grid_with(&[(1, 2), (2, 2), (3, 2)]);
+        grid.step();
+        assert!(lit(&grid, &[(2, 1), (2, 2), (2, 3)]));
+        assert!(!grid.grid[Point::new(1, 2)] && !grid.grid[Point::new(3, 2)]);
+        grid.step();
+        assert!(lit(&grid, &[(1, 2), (2, 2), (3, 2)]));
+        assert_eq!(grid.count_on(), 3);
+    }
+
+    #[test]
+    fn pinned_lights_stay_on() {
+        let mut grid = grid_with(&[]);
+        grid.pin(0, 0);
+        grid.step_n(5);
+        assert!(lit(&grid, &[(0, 0)]));
+        assert_eq!(grid.count_on(), 1);
+    }
+
+    #[test]
+    fn example_animation() {
+        const EXAMPLE: &str = "\
+.#.#.#
+...##.
+#....#
+..#...
+#.#..#
+####..";
+        let steps = |steps| Day18 {
+            config: Config { steps },
+        };
+        assert_eq!(part_1(&steps(4), EXAMPLE), 4);
+        assert_eq!(part_2(&steps(5), EXAMPLE), 17);
+    }
+
+    #[test]
+    fn answers_match() {
+        assert_eq!(answers(&Day18::PUZZLE, 18), ["1061", "1006"]);
+    }
+}
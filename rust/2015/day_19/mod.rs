@@ -0,0 +1,202 @@
+use crate::{input::PuzzleInput, random::Rng, Error, Solution};
+use std::collections::{BTreeSet, HashMap, HashSet};
+
+type Molecule<'a> = &'a str;
+type Replacement<'a> = (&'a str, &'a str);
+type ReplacementMap<'a> = HashMap<&'a str, Vec<&'a str>>;
+
+fn apply_replacement(molecule: Molecule, (key, value): Replacement, index: usize) -> String {
+    let mut generated_molecule = String::from(molecule);
+    generated_molecule.replace_range(index..index + key.len(), value);
+    generated_molecule
+}
+
+fn get_unique_molecules(replacements: &ReplacementMap, molecule: Molecule) -> HashSet<String> {
+    let mut unique_molecules = HashSet::new();
+
+    for (key, values) in replacements {
+        for i in 0..molecule.len().saturating_sub(key.len() - 1) {
+            if molecule[i..].starts_with(key) {
+                for value in values {
+                    unique_molecules.insert(apply_replacement(molecule, (key, value), i));
+                }
+            }
+        }
+    }
+
+    unique_molecules
+}
+
+fn fewest_steps_to_electron(replacements: &ReplacementMap, molecule: Molecule) -> usize {
+    // Create reverse map where values are keys and keys are values
+    let reverse_map: ReplacementMap = replacements
+        .iter()
+        .flat_map(|(key, values)| values.iter().map(|value| (*value, vec![*key])))
+        .fold(HashMap::new(), |mut acc, (value, key)| {
+            acc.entry(value).or_default().extend(key);
+            acc
+        });
+
+    // Ordered sets keep the pruning below deterministic between runs
+    let mut current_molecules = BTreeSet::from([molecule.to_string()]);
+    let mut steps = 0;
+
+    while !current_molecules.contains("e") {
+        steps += 1;
+        let new_molecules: BTreeSet<String> = current_molecules
+            .iter()
+            .flat_map(|molecule| get_unique_molecules(&reverse_map, molecule))
+            .collect();
+
+        if new_molecules.is_empty() {
+            panic!("No solution found");
+        }
+
+        current_molecules = new_molecules
+            .iter()
+            .filter(|m| m.len() == new_molecules.iter().min_by_key(|m| m.len()).unwrap().len())
+            .rev()
+            .take(7)
+            .cloned()
+            .collect();
+    }
+
+    steps
+}
+
+/// Elements for generated molecules. No one-letter element is the first letter
+/// of a two-letter one, so an element is never found inside another.
+const ELEMENTS: [&str; 12] = [
+    "Al", "B", "Ca", "F", "H", "Mg", "N", "O", "P", "Si", "Th", "Ti",
+];
+
+/// Replacements, and a molecule of `size` elements grown from `e` with them.
+/// `e` only becomes a first and a last element, which grow by taking on a
+/// neighbour towards the middle, and the elements between grow into pairs that
+/// keep themselves. Undoing any replacement leaves a molecule of that same
+/// shape, so the search for part 2 never gets stuck.
+fn generate_replacements(rng: &mut Rng, size: usize) -> String {
+    let mut elements = ELEMENTS.to_vec();
+    rng.shuffle(&mut elements);
+    elements.truncate(rng.range(4..=8));
+    let (first, last, middle) = (elements[0], elements[1], &elements[2..]);
+
+    let mut rules = vec![("e", [first, last])];
+    for &element in middle {
+        rules.push((first, [first, element]));
+        rules.push((last, [element, last]));
+        let before = rules.len();
+        for &other in middle {
+            if rng.chance(1, 3) {
+                rules.push((element, [element, other]));
+            }
+            if rng.chance(1, 3) {
+                rules.push((element, [other, element]));
+            }
+        }
+        if rules.len() == before {
+            rules.push((element, [element, element]));
+        }
+    }
+    rules.sort();
+    rules.dedup();
+
+    let mut molecule = vec![first, last];
+    while molecule.len() < size {
+        let i = rng.range(0..=molecule.len() - 1);
+        let grown: Vec<[&str; 2]> = rules
+            .iter()
+            .filter(|&&(from, _)| from == molecule[i])
+            .map(|&(_, to)| to)
+            .collect();
+        molecule.splice(i..=i, *rng.choose(&grown));
+    }
+
+    let rules: Vec<String> = rules
+        .iter()
+        .map(|(from, [a, b])| format!("{from} => {a}{b}"))
+        .collect();
+    format!("{}\n\n{}", rules.join("\n"), molecule.concat())
+}
+
+#[derive(Clone)]
+pub struct Day19;
+
+impl Solution for Day19 {
+    type Input<'a> = (ReplacementMap<'a>, Molecule<'a>);
+    type Output1 = usize;
+    type Output2 = usize;
+
+    const PUZZLE_SIZE: usize = 250;
+
+    fn parse<'a>(&self, input: &'a PuzzleInput) -> Result<Self::Input<'a>, Error> {
+        let molecule: Molecule = input
+            .lines()
+            .last()
+            .ok_or_else(|| Error::new("Missing molecule"))?;
+        let replacements: ReplacementMap = input
+            .lines()
+            .take_while(|line| !line.is_empty())
+            .map(|line| {
+                line.split_once(" => ")
+                    .ok_or_else(|| Error::at(line, "Expected <from> => <to>"))
+            })
+            .try_fold(HashMap::new(), |mut acc: ReplacementMap, replacement| {
+                let (key, value) = replacement?;
+                acc.entry(key).or_default().push(value);
+                Ok::<_, Error>(acc)
+            })?;
+        Ok((replacements, molecule))
+    }
+
+    fn part_1(&self, (replacements, molecule): &Self::Input<'_>) -> usize {
+        get_unique_molecules(replacements, molecule).len()
+    }
+
+    fn part_2(&self, (replacements, molecule): &Self::Input<'_>) -> usize {
+        fewest_steps_to_electron(replacements, molecule)
+    }
+
+    fn generate(&self, rng: &mut Rng, size: usize) -> PuzzleInput {
+        PuzzleInput::new(generate_replacements(rng, size))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{answers, part_1, part_2};
+
+    const REPLACEMENTS: &str = "\
+e => H
+e => O
+H => HO
+H => OH
+O => HH";
+
+    #[test]
+    fn distinct_molecules() {
+        assert_eq!(part_1(&Day19, &format!("{REPLACEMENTS}\n\nHOH")), 4);
+        assert_eq!(part_1(&Day19, &format!("{REPLACEMENTS}\n\nHOHOHO")), 7);
+    }
+
+    #[test]
+    fn steps_from_electron() {
+        assert_eq!(part_2(&Day19, &format!("{REPLACEMENTS}\n\nHOH")), 3);
+        assert_eq!(part_2(&Day19, &format!("{REPLACEMENTS}\n\nHOHOHO")), 6);
+    }
+
+    #[test]
+    fn generated_molecules_come_from_an_electron() {
+        for seed in 0..5 {
+            let input = Day19.generate(&mut Rng::new(seed), 60);
+            // Every replacement adds one element to the two `e` starts with
+            assert_eq!(part_2(&Day19, &input), 59);
+        }
+    }
+
+    #[test]
+    fn answers_match() {
+        assert_eq!(answers(&Day19, 19), ["509", "195"]);
+    }
+}
@@ -0,0 +1,150 @@
+use crate::{
+    input::PuzzleInput, parse::parse_number, progress::Progress, random::Rng, Error, Solution,
+};
+
+/// The first house to get at least `target` presents, when every elf brings
+/// as many presents as their number to the first `visits` multiples of it.
+/// House `target`'s own elf brings enough, so the sieve can stop there, and
+/// even a target of 0 is first met by house 1.
+fn first_house(target: usize, visits: usize) -> usize {
+    let target = target.max(1);
+    let mut presents = vec![0; target + 1];
+    // Later elves visit fewer houses, so the elves done so far say little
+    // about how long the rest will take, and there is no total for an ETA
+    let progress = Progress::new("Sieving");
+    for elf in 1..=target {
+        for house in (elf..=target).step_by(elf).take(visits) {
+            presents[house] += elf;
+        }
+        if elf.is_multiple_of(1024) {
+            progress.add(1024);
+        }
+    }
+    progress.finish();
+    (1..=target)
+        .find(|&house| presents[house] >= target)
+        .unwrap_or(usize::MIN)
+}
+
+#[cfg(test)]
+fn get_factor_list(number: usize) -> Vec<usize> {
+    let mut factors: Vec<usize> = vec![1];
+    if number > 1 {
+        factors.push(number);
+    }
+
+    let mut i = 2;
+    while i * i <= number {
+        if number.is_multiple_of(i) {
+            factors.push(i);
+            if i * i != number {
+                factors.push(number / i);
+            }
+        }
+        i += 1;
+    }
+
+    factors
+}
+
+/// The first house to get at least `target` presents when every elf brings
+/// `per_elf` times their number to the first `visits` multiples of it, adding
+/// up each house's presents by trial division: much slower than
+/// [`first_house`], but simple enough to check it against.
+#[cfg(test)]
+fn first_house_by_trial_division(target: usize, per_elf: usize, visits: usize) -> usize {
+    (1..)
+        .find(|&house| {
+            let presents: usize = get_factor_list(house)
+                .into_iter()
+                .filter(|&elf| elf.saturating_mul(visits) >= house)
+                .map(|elf| elf * per_elf)
+                .sum();
+            presents >= target
+        })
+        .unwrap()
+}
+
+#[derive(Clone)]
+pub struct Day20;
+
+impl Solution for Day20 {
+    type Input<'a> = usize;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    const PUZZLE_SIZE: usize = 36_000_000;
+
+    fn parse<'a>(&self, input: &'a PuzzleInput) -> Result<Self::Input<'a>, Error> {
+        parse_number(input.trim(), "number of presents")
+    }
+
+    fn part_1(&self, target: &usize) -> usize {
+        first_house(target.div_ceil(10), usize::MAX)
+    }
+
+    fn part_2(&self, target: &usize) -> usize {
+        first_house(target.div_ceil(11), 50)
+    }
+
+    /// A target of between half of `size` and `size` presents.
+    fn generate(&self, rng: &mut Rng, size: usize) -> PuzzleInput {
+        PuzzleInput::new(rng.range(size / 2..=size).to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        check::Checker,
+        testing::{answers, part_1, part_2},
+    };
+
+    #[test]
+    fn first_house_with_enough_presents() {
+        assert_eq!(part_1(&Day20, "70"), 4);
+        assert_eq!(part_1(&Day20, "120"), 6);
+        assert_eq!(part_1(&Day20, "150"), 8);
+    }
+
+    #[test]
+    fn targets_between_multiples_of_ten() {
+        // House 1 gets only 10 or 11 presents, so 15 needs house 2
+        assert_eq!(part_1(&Day20, "15"), 2);
+        assert_eq!(part_2(&Day20, "15"), 2);
+        for target in ["0", "1", "9"] {
+            assert_eq!(part_1(&Day20, target), 1);
+            assert_eq!(part_2(&Day20, target), 1);
+        }
+    }
+
+    #[test]
+    fn factors() {
+        let mut factors = get_factor_list(12);
+        factors.sort();
+        assert_eq!(factors, [1, 2, 3, 4, 6, 12]);
+        assert_eq!(get_factor_list(1), [1]);
+    }
+
+    #[test]
+    fn sieve_matches_trial_division() {
+        Checker::new(&Day20)
+            .max_size(20_000)
+            .differential(
+                |&target| {
+                    (
+                        first_house_by_trial_division(target, 10, usize::MAX),
+                        first_house_by_trial_division(target, 11, 50),
+                    )
+                },
+                |target| (Day20.part_1(target), Day20.part_2(target)),
+            )
+            .unwrap();
+    }
+
+    #[test]
+    fn answers_match() {
+        assert_eq!(answers(&Day20, 20), ["831600", "884520"]);
+    }
+}
@@ -0,0 +1,397 @@
+use crate::{
+    input::PuzzleInput,
+    parse::{key_values, parse_number, Pattern},
+    random::Rng,
+    Error, Solution,
+};
+use serde_json::{json, Value};
+
+const ITEM: Pattern = Pattern::new("{name}, {cost}, {damage}, {armor}");
+
+/// The shop every player visits, as the puzzle text describes it.
+const SHOP: &str = "\
+Dagger, 8, 4, 0
+Shortsword, 10, 5, 0
+Warhammer, 25, 6, 0
+Longsword, 40, 7, 0
+Greataxe, 74, 8, 0
+
+Leather, 13, 0, 1
+Chainmail, 31, 0, 2
+Splintmail, 53, 0, 3
+Bandedmail, 75, 0, 4
+Platemail, 102, 0, 5
+
+Damage +1, 25, 1, 0
+Damage +2, 50, 2, 0
+Damage +3, 100, 3, 0
+Defense +1, 20, 0, 1
+Defense +2, 40, 0, 2
+Defense +3, 80, 0, 3";
+
+const BOSS_STATS: [&str; 3] = ["Hit Points", "Damage", "Armor"];
+
+#[derive(Debug, Clone, Copy, Default)]
+struct Item<'a> {
+    name: &'a str,
+    cost: u32,
+    damage: u32,
+    armor: u32,
+}
+
+impl<'a> Item<'a> {
+    fn from_str(line: &'a str) -> Result<Self, Error> {
+        let (name, cost, damage, armor) = ITEM.parse(line)?;
+        Ok(Item {
+            name,
+            cost,
+            damage,
+            armor,
+        })
+    }
+}
+
+#[derive(Debug)]
+enum BattleResult {
+    Win,
+    Loss,
+}
+
+impl std::fmt::Display for BattleResult {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+trait Character {
+    fn hit_points(&self) -> u32;
+    fn damage(&self) -> u32;
+    fn armor(&self) -> u32;
+    fn kills_in_turn(&self, other: &dyn Character) -> u32 {
+        let damage = self.damage().saturating_sub(other.armor()).max(1);
+        other.hit_points().div_ceil(damage)
+    }
+    fn battle(&self, other: &dyn Character) -> BattleResult
+    where
+        Self: Sized,
+    {
+        if self.kills_in_turn(other) <= other.kills_in_turn(self) {
+            BattleResult::Win
+        } else {
+            BattleResult::Loss
+        }
+    }
+}
+
+struct CharacterStats {
+    hit_points: u32,
+    damage: u32,
+    armor: u32,
+}
+
+struct Player<'a> {
+    stats: CharacterStats,
+    weapon: Item<'a>,
+    armor: Item<'a>,
+    rings: [Item<'a>; 2],
+}
+
+impl Player<'_> {
+    fn new(stats: CharacterStats) -> Self {
+        Player {
+            stats,
+            weapon: Item::default(),
+            armor: Item::default(),
+            rings: [Item::default(), Item::default()],
+        }
+    }
+
+    fn equipment_cost(&self) -> u32 {
+        self.weapon.cost + self.armor.cost + self.rings[0].cost + self.rings[1].cost
+    }
+}
+
+impl Character for Player<'_> {
+    fn hit_points(&self) -> u32 {
+        self.stats.hit_points
+    }
+
+    fn damage(&self) -> u32 {
+        self.stats.damage + self.weapon.damage + self.rings[0].damage + self.rings[1].damage
+    }
+
+    fn armor(&self) -> u32 {
+        self.stats.armor + self.armor.armor + self.rings[0].armor + self.rings[1].armor
+    }
+}
+
+pub struct Boss {
+    stats: CharacterStats,
+}
+
+impl Boss {
+    fn from_str(input: &str) -> Result<Self, Error> {
+        let stats = key_values(input.trim(), "\n", ": ")?.restrict_to(&BOSS_STATS)?;
+        Ok(Boss {
+            stats: CharacterStats {
+                hit_points: stats.require("Hit Points")?,
+                damage: stats.require("Damage")?,
+                armor: stats.require("Armor")?,
+            },
+        })
+    }
+}
+
+impl Character for Boss {
+    fn hit_points(&self) -> u32 {
+        self.stats.hit_points
+    }
+
+    fn damage(&self) -> u32 {
+        self.stats.damage
+    }
+
+    fn armor(&self) -> u32 {
+        self.stats.armor
+    }
+}
+
+pub struct Shop<'a> {
+    weapons: [Item<'a>; 5],
+    armors: [Item<'a>; 6],
+    rings: [Item<'a>; 7],
+}
+
+fn parse_shop(input: &str) -> Result<Shop<'_>, Error> {
+    let mut groups = input.split("\n\n").map(str::trim);
+    let mut items = |kind: &str| {
+        groups
+            .next()
+            .ok_or_else(|| Error::new(format!("Missing {kind} in the shop")))?
+            .lines()
+            .map(Item::from_str)
+            .collect::<Result<Vec<_>, Error>>()
+    };
+    let (weapons, armors, rings) = (items("weapons")?, items("armor")?, items("rings")?);
+
+    // Armor and rings are optional, which the empty item at the end of each list stands for
+    let wrong_count =
+        |kind: &str, expected: usize| Error::new(format!("The shop must sell {expected} {kind}"));
+    Ok(Shop {
+        weapons: weapons.try_into().map_err(|_| wrong_count("weapons", 5))?,
+        armors: [armors, vec![Item::default()]]
+            .concat()
+            .try_into()
+            .map_err(|_| wrong_count("armors", 5))?,
+        rings: [rings, vec![Item::default()]]
+            .concat()
+            .try_into()
+            .map_err(|_| wrong_count("rings", 6))?,
+    })
+}
+
+/// The equipment found for one part, displayed as its total cost.
+pub struct Loadout {
+    cost: u32,
+    item_names: Vec<String>,
+}
+
+impl Loadout {
+    fn new(cost: u32, equipment: [Item; 4]) -> Self {
+        Loadout {
+            cost,
+            item_names: equipment
+                .iter()
+                .map(|item| item.name.to_string())
+                .filter(|name| !name.is_empty())
+                .collect(),
+        }
+    }
+
+    pub fn item_names(&self) -> impl Iterator<Item = &str> {
+        self.item_names.iter().map(String::as_str)
+    }
+
+    fn details(&self) -> Value {
+        json!({ "cost": self.cost, "items": self.item_names })
+    }
+}
+
+impl std::fmt::Display for Loadout {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.cost)
+    }
+}
+
+fn new_player<'a>(hit_points: u32) -> Player<'a> {
+    Player::new(CharacterStats {
+        hit_points,
+        damage: 0,
+        armor: 0,
+    })
+}
+
+/// The player's hit points before buying anything.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Config {
+    pub hit_points: u32,
+}
+
+impl Config {
+    pub const PUZZLE: Config = Config { hit_points: 100 };
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config::PUZZLE
+    }
+}
+
+#[derive(Clone, Default)]
+pub struct Day21 {
+    pub config: Config,
+}
+
+impl Day21 {
+    pub const PUZZLE: Day21 = Day21 {
+        config: Config::PUZZLE,
+    };
+}
+
+impl Solution for Day21 {
+    type Input<'a> = (Shop<'a>, Boss);
+    type Output1 = Loadout;
+    type Output2 = Loadout;
+
+    const INPUT_FILES: &'static [&'static str] = &["boss", "shop"];
+    const PUZZLE_SIZE: usize = 100;
+    const PARAMETERS: &'static [&'static str] = &["hit_points"];
+
+    fn parse<'a>(&self, input: &'a PuzzleInput) -> Result<Self::Input<'a>, Error> {
+        Ok((parse_shop(input.file("shop")?)?, Boss::from_str(input)?))
+    }
+
+    fn part_1(&self, (shop, boss): &Self::Input<'_>) -> Loadout {
+        let mut player = new_player(self.config.hit_points);
+        let mut min_cost = u32::MAX;
+        let mut min_equipment = [Item::default(); 4];
+        for weapon in shop.weapons.iter() {
+            player.weapon = *weapon;
+            for armor in shop.armors.iter() {
+                player.armor = *armor;
+                for ring1 in shop.rings.iter() {
+                    player.rings[0] = *ring1;
+                    for ring2 in shop.rings.iter() {
+                        if ring1.name == ring2.name {
+                            continue;
+                        }
+                        player.rings[1] = *ring2;
+                        match player.battle(boss) {
+                            BattleResult::Win => {
+                                let cost = player.equipment_cost();
+                                if cost < min_cost {
+                                    min_cost = cost;
+                                    min_equipment = [
+                                        player.weapon,
+                                        player.armor,
+                                        player.rings[0],
+                                        player.rings[1],
+                                    ];
+                                }
+                            }
+                            BattleResult::Loss => {}
+                        }
+                    }
+                }
+            }
+        }
+
+        Loadout::new(min_cost, min_equipment)
+    }
+
+    fn part_2(&self, (shop, boss): &Self::Input<'_>) -> Loadout {
+        let mut player = new_player(self.config.hit_points);
+        let mut max_cost = u32::MIN;
+        let mut max_equipment = [Item::default(); 4];
+        for weapon in shop.weapons.iter() {
+            player.weapon = *weapon;
+            for armor in shop.armors.iter() {
+                player.armor = *armor;
+                for ring1 in shop.rings.iter() {
+                    player.rings[0] = *ring1;
+                    for ring2 in shop.rings.iter() {
+                        if ring1.name == ring2.name {
+                            continue;
+                        }
+                        player.rings[1] = *ring2;
+                        match player.battle(boss) {
+                            BattleResult::Win => {}
+                            BattleResult::Loss => {
+                                let cost = player.equipment_cost();
+                                if cost > max_cost {
+                                    max_cost = cost;
+                                    max_equipment = [
+                                        player.weapon,
+                                        player.armor,
+                                        player.rings[0],
+                                        player.rings[1],
+                                    ];
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        Loadout::new(max_cost, max_equipment)
+    }
+
+    fn details_1(&self, loadout: &Loadout) -> Option<Value> {
+        Some(loadout.details())
+    }
+
+    fn details_2(&self, loadout: &Loadout) -> Option<Value> {
+        Some(loadout.details())
+    }
+
+    /// A boss with `size` hit points, 4 to 9 damage and 0 to 3 armor, and the
+    /// shop from the puzzle text.
+    fn generate(&self, rng: &mut Rng, size: usize) -> PuzzleInput {
+        let boss = format!(
+            "Hit Points: {size}\nDamage: {}\nArmor: {}",
+            rng.range(4..=9),
+            rng.range(0..=3)
+        );
+        PuzzleInput::named("boss", boss).with_file("shop", SHOP)
+    }
+
+    fn configure(&mut self, _name: &str, value: &str) -> Result<(), Error> {
+        self.config.hit_points = parse_number(value, "number of hit points")?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::answers;
+
+    #[test]
+    fn example_battle() {
+        let player = Player::new(CharacterStats {
+            hit_points: 8,
+            damage: 5,
+            armor: 5,
+        });
+        let boss = Boss::from_str("Hit Points: 12\nDamage: 7\nArmor: 2").unwrap();
+        assert_eq!(player.kills_in_turn(&boss), 4);
+        assert_eq!(boss.kills_in_turn(&player), 4);
+        assert!(matches!(player.battle(&boss), BattleResult::Win));
+    }
+
+    #[test]
+    fn answers_match() {
+        assert_eq!(answers(&Day21::PUZZLE, 21), ["111", "188"]);
+    }
+}
@@ -1,4 +1,4 @@
-use crate::spell::{Spell, SpellType};
+use super::spell::{Spell, SpellType};
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Effect {
@@ -1,6 +1,6 @@
-use crate::effect::Effect;
-use crate::game_state::{BossStats, GameDifficulty, GameState, PlayerStats, TurnResult};
-use crate::spell::{Spell, SpellType};
+use super::effect::Effect;
+use super::game_state::{BossStats, GameDifficulty, GameState, PlayerStats, TurnResult};
+use super::spell::{Spell, SpellType};
 
 fn get_start_of_turn_log(game_state: &GameState, turn: &str, player: &PlayerStats) -> String {
     let mut log = String::new();
@@ -17,7 +17,7 @@ fn get_start_of_turn_log(game_state: &GameState, turn: &str, player: &PlayerStat
 }
 
 fn get_effects_log(
-    effects: &Vec<Effect>,
+    effects: &[Effect],
     mut player: PlayerStats,
     mut boss: BossStats,
 ) -> (String, PlayerStats, BossStats) {
@@ -107,8 +107,8 @@ pub fn create_game_log(
     for spell in spell_history {
         let (effects_log, player, boss) = get_effects_log(
             &replay_state.active_effects,
-            replay_state.player.clone(),
-            replay_state.boss.clone(),
+            replay_state.player,
+            replay_state.boss,
         );
         log.push_str(&get_start_of_turn_log(
             &replay_state,
@@ -117,7 +117,7 @@ pub fn create_game_log(
         ));
         let spell_log = get_spell_log(spell, &player, &boss);
 
-        match replay_state.make_player_turn(&spell).unwrap() {
+        match replay_state.make_player_turn(spell).unwrap() {
             TurnResult::BossKilledByEffects => {
                 log.push_str(&effects_log);
                 log.push_str(&format!(
@@ -136,7 +136,7 @@ pub fn create_game_log(
                 return log;
             }
             TurnResult::PlayerKilled => {
-                log.push_str(&format!("This kills the player, and the boss wins.\n"));
+                log.push_str("This kills the player, and the boss wins.\n");
                 return log;
             }
             _ => {
@@ -147,8 +147,8 @@ pub fn create_game_log(
 
         let (effects_log, player, boss) = get_effects_log(
             &replay_state.active_effects,
-            replay_state.player.clone(),
-            replay_state.boss.clone(),
+            replay_state.player,
+            replay_state.boss,
         );
         log.push_str(&get_start_of_turn_log(&replay_state, "Boss turn", &player));
         log.push_str(&effects_log);
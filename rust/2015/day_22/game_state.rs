@@ -1,5 +1,5 @@
-use crate::effect::Effect;
-use crate::spell::{Spell, SpellType};
+use super::effect::Effect;
+use super::spell::{Spell, SpellType};
 
 #[derive(Clone, Copy, PartialEq, Eq)]
 pub enum GameDifficulty {
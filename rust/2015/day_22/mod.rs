@@ -0,0 +1,213 @@
+mod effect;
+mod game_log;
+mod game_state;
+mod solver;
+mod spell;
+
+use crate::{
+    input::PuzzleInput,
+    parse::{key_values, parse_number},
+    progress::{Progress, ProgressStyle},
+    random::Rng,
+    Error, Found, Solution,
+};
+use game_log::create_game_log;
+use game_state::{BossStats, GameDifficulty, GameState, PlayerStats};
+use serde_json::{json, Value};
+use solver::find_minimum_mana_solution;
+
+/// The cheapest winning game, displayed as the mana it cost.
+pub struct Victory {
+    pub mana_spent: i32,
+    pub log: String,
+}
+
+impl Victory {
+    fn details(&self) -> Value {
+        let log: Vec<&str> = self.log.lines().filter(|line| !line.is_empty()).collect();
+        json!({ "mana_spent": self.mana_spent, "log": log })
+    }
+}
+
+impl std::fmt::Display for Victory {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.mana_spent)
+    }
+}
+
+fn play_game(
+    player_stats: PlayerStats,
+    boss_stats: BossStats,
+    difficulty: GameDifficulty,
+) -> Found<Victory> {
+    let initial_state = GameState::new(player_stats, boss_stats, difficulty);
+    let progress = Progress::new("Searching");
+    let Some(winning_state) = find_minimum_mana_solution(initial_state, &progress) else {
+        return Found::Nothing("the player can't win");
+    };
+    Found::Answer(Victory {
+        mana_spent: winning_state.mana_spent,
+        log: create_game_log(
+            &winning_state.spell_history,
+            player_stats,
+            boss_stats,
+            difficulty,
+        ),
+    })
+}
+
+fn parse_boss_stats(input: &str) -> Result<BossStats, Error> {
+    let stats = key_values(input.trim(), "\n", ": ")?.restrict_to(&["Hit Points", "Damage"])?;
+    Ok(BossStats {
+        hp: stats.require("Hit Points")?,
+        damage: stats.require("Damage")?,
+    })
+}
+
+/// What the player starts with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Config {
+    pub hit_points: i32,
+    pub mana: i32,
+}
+
+impl Config {
+    pub const PUZZLE: Config = Config {
+        hit_points: 50,
+        mana: 500,
+    };
+}
+
+impl Config {
+    fn player(self) -> PlayerStats {
+        PlayerStats {
+            hp: self.hit_points,
+            armor: 0,
+            mana: self.mana,
+        }
+    }
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config::PUZZLE
+    }
+}
+
+#[derive(Clone, Default)]
+pub struct Day22 {
+    pub config: Config,
+}
+
+impl Day22 {
+    pub const PUZZLE: Day22 = Day22 {
+        config: Config::PUZZLE,
+    };
+}
+
+impl Solution for Day22 {
+    type Input<'a> = BossStats;
+    type Output1 = Found<Victory>;
+    type Output2 = Found<Victory>;
+
+    const PUZZLE_SIZE: usize = 58;
+    const PARAMETERS: &'static [&'static str] = &["hit_points", "mana"];
+
+    fn parse<'a>(&self, input: &'a PuzzleInput) -> Result<Self::Input<'a>, Error> {
+        parse_boss_stats(input)
+    }
+
+    fn part_1(&self, boss_stats: &BossStats) -> Found<Victory> {
+        play_game(self.config.player(), *boss_stats, GameDifficulty::Normal)
+    }
+
+    fn part_2(&self, boss_stats: &BossStats) -> Found<Victory> {
+        play_game(self.config.player(), *boss_stats, GameDifficulty::Hard)
+    }
+
+    fn details_1(&self, victory: &Found<Victory>) -> Option<Value> {
+        match victory {
+            Found::Answer(victory) => Some(victory.details()),
+            Found::Nothing(_) => None,
+        }
+    }
+
+    fn details_2(&self, victory: &Found<Victory>) -> Option<Value> {
+        self.details_1(victory)
+    }
+
+    /// A boss with `size` hit points. Its damage starts at 5 to 10 and comes
+    /// down until there is a way to win on hard, if any damage allows one.
+    fn generate(&self, rng: &mut Rng, size: usize) -> PuzzleInput {
+        let mut boss = BossStats {
+            hp: size as i32,
+            damage: rng.range(5..=10),
+        };
+        // These searches are part of making the input, not solving it
+        let progress = Progress::new("Searching").style(ProgressStyle::Silent);
+        while boss.damage > 1
+            && find_minimum_mana_solution(
+                GameState::new(self.config.player(), boss, GameDifficulty::Hard),
+                &progress,
+            )
+            .is_none()
+        {
+            boss.damage -= 1;
+        }
+        PuzzleInput::new(format!("Hit Points: {}\nDamage: {}", boss.hp, boss.damage))
+    }
+
+    fn configure(&mut self, name: &str, value: &str) -> Result<(), Error> {
+        match name {
+            "hit_points" => self.config.hit_points = parse_number(value, "number of hit points")?,
+            _ => self.config.mana = parse_number(value, "amount of mana")?,
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{answers, part_1};
+
+    const EXAMPLE: Day22 = Day22 {
+        config: Config {
+            hit_points: 10,
+            mana: 250,
+        },
+    };
+
+    #[test]
+    fn example_games() {
+        assert_eq!(
+            part_1(&EXAMPLE, "Hit Points: 13\nDamage: 8")
+                .unwrap()
+                .mana_spent,
+            226
+        );
+        assert_eq!(
+            part_1(&EXAMPLE, "Hit Points: 14\nDamage: 8")
+                .unwrap()
+                .mana_spent,
+            641
+        );
+        assert_eq!(
+            part_1(&EXAMPLE, "Hit Points: 500\nDamage: 8").to_string(),
+            "none (the player can't win)"
+        );
+    }
+
+    #[test]
+    fn details_include_the_game_log() {
+        let victory = part_1(&EXAMPLE, "Hit Points: 13\nDamage: 8");
+        let details = EXAMPLE.details_1(&victory).unwrap();
+        assert_eq!(details["mana_spent"], 226);
+        assert_eq!(details["log"][0], "-- Player turn --");
+    }
+
+    #[test]
+    fn answers_match() {
+        assert_eq!(answers(&Day22::PUZZLE, 22), ["1269", "1309"]);
+    }
+}
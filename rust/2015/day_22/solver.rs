@@ -1,5 +1,6 @@
-use crate::game_state::{GameState, TurnResult};
-use crate::spell::Spell;
+use super::game_state::{GameState, TurnResult};
+use super::spell::Spell;
+use crate::progress::Progress;
 use std::cmp::Ordering;
 use std::collections::BinaryHeap;
 
@@ -18,19 +19,38 @@ impl PartialOrd for GameStateWrapper {
     }
 }
 
-pub fn find_minimum_mana_solution(initial_state: GameState) -> Option<GameState> {
+/// How many states are searched between updates of the progress.
+const PROGRESS_BATCH: u64 = 1024;
+
+/// The cheapest way to win from `initial_state`, reporting the search on
+/// `progress`.
+pub fn find_minimum_mana_solution(
+    initial_state: GameState,
+    progress: &Progress,
+) -> Option<GameState> {
     let mut queue = BinaryHeap::new();
     queue.push(GameStateWrapper(initial_state));
     let spells = Spell::all_spells();
     let mut discarded_states = 0;
+    let mut searched: u64 = 0;
 
     while let Some(GameStateWrapper(current_state)) = queue.pop() {
+        searched += 1;
+        if searched.is_multiple_of(PROGRESS_BATCH) {
+            progress.add_with(PROGRESS_BATCH, || {
+                format!("{discarded_states} discarded, {} queued", queue.len())
+            });
+        }
         for spell in &spells {
             let mut new_state = current_state.clone();
             match new_state.make_turn(spell) {
                 Ok(TurnResult::BossKilledByEffects) | Ok(TurnResult::BossKilledBySpell) => {
-                    println!("\nDiscarded {} states", discarded_states);
-                    println!("Paths left: {}", queue.len());
+                    progress.finish_with(|| {
+                        format!(
+                            "discarded {discarded_states} states, {} paths left",
+                            queue.len()
+                        )
+                    });
                     return Some(new_state);
                 }
                 Ok(TurnResult::Ongoing) => {
@@ -40,5 +60,6 @@ pub fn find_minimum_mana_solution(initial_state: GameState) -> Option<GameState>
             }
         }
     }
+    progress.finish();
     None
 }
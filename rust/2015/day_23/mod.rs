@@ -0,0 +1,227 @@
+use crate::{input::PuzzleInput, parse::parse_number, random::Rng, Error, Solution};
+use std::collections::HashMap;
+
+#[derive(Default, Debug)]
+struct Register {
+    value: usize,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum Instruction<'a> {
+    Hlf(&'a str),
+    Tpl(&'a str),
+    Inc(&'a str),
+    Jmp(isize),
+    Jie(&'a str, isize),
+    Jio(&'a str, isize),
+}
+
+impl<'a> Instruction<'a> {
+    fn from_str(line: &'a str) -> Result<Self, Error> {
+        let (instruction, params) = line
+            .split_once(' ')
+            .map(|(i, p)| (i.trim(), p.trim()))
+            .ok_or_else(|| Error::at(line, "Expected <instruction> <parameters>"))?;
+        let register_and_offset = || {
+            params
+                .split_once(", ")
+                .ok_or_else(|| Error::at(params, "Expected <register>, <offset>"))
+                .and_then(|(r, o)| Ok((r.trim(), parse_number(o.trim(), "offset")?)))
+        };
+
+        Ok(match instruction {
+            "hlf" => Instruction::Hlf(params),
+            "tpl" => Instruction::Tpl(params),
+            "inc" => Instruction::Inc(params),
+            "jmp" => Instruction::Jmp(parse_number(params, "offset")?),
+            "jie" => {
+                let (register, offset) = register_and_offset()?;
+                Instruction::Jie(register, offset)
+            }
+            "jio" => {
+                let (register, offset) = register_and_offset()?;
+                Instruction::Jio(register, offset)
+            }
+            _ => return Err(Error::at(instruction, "Invalid instruction")),
+        })
+    }
+}
+
+struct Program<'a> {
+    registers: HashMap<&'a str, Register>,
+    instructions: Vec<Instruction<'a>>,
+}
+
+impl<'a> Program<'a> {
+    fn new(instructions: &[Instruction<'a>]) -> Self {
+        Self {
+            registers: HashMap::new(),
+            instructions: instructions.to_vec(),
+        }
+    }
+
+    fn run(&mut self) {
+        let mut line: usize = 0;
+        while line < self.instructions.len() {
+            match self.instructions[line] {
+                Instruction::Hlf(register) => {
+                    self.registers.entry(register).or_default().value /= 2;
+                    line += 1;
+                }
+                Instruction::Tpl(register) => {
+                    self.registers.entry(register).or_default().value *= 3;
+                    line += 1;
+                }
+                Instruction::Inc(register) => {
+                    self.registers.entry(register).or_default().value += 1;
+                    line += 1;
+                }
+                Instruction::Jmp(offset) => {
+                    line = offset.checked_add(line as isize).unwrap_or_default() as usize;
+                }
+                Instruction::Jie(register, offset) => {
+                    if self
+                        .registers
+                        .entry(register)
+                        .or_default()
+                        .value
+                        .is_multiple_of(2)
+                    {
+                        line = offset.checked_add(line as isize).unwrap_or_default() as usize;
+                    } else {
+                        line += 1;
+                    }
+                }
+                Instruction::Jio(register, offset) => {
+                    if self.registers.entry(register).or_default().value == 1 {
+                        line = offset.checked_add(line as isize).unwrap_or_default() as usize;
+                    } else {
+                        line += 1;
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Up to `size` `inc` and `tpl` instructions building a positive starting
+/// number from `a`, kept small enough that its Collatz sequence fits in a
+/// `usize`.
+fn generate_start(rng: &mut Rng, size: usize, mut a: u64) -> Vec<&'static str> {
+    const LIMIT: u64 = 1_000_000_000;
+    let mut instructions = Vec::new();
+    for _ in 0..size {
+        if a > 0 && a * 3 <= LIMIT && rng.chance(1, 2) {
+            a *= 3;
+            instructions.push("tpl a");
+        } else if a < LIMIT {
+            a += 1;
+            instructions.push("inc a");
+        }
+    }
+    if a == 0 {
+        instructions.push("inc a");
+    }
+    instructions
+}
+
+/// A program shaped like ours: each part builds its own starting number in
+/// `a`, then a shared loop counts the Collatz steps from it to 1 in `b`.
+fn generate_program(rng: &mut Rng, size: usize) -> String {
+    let part_1 = generate_start(rng, size, 0);
+    let part_2 = generate_start(rng, size, 1);
+
+    let mut program = vec![format!("jio a, +{}", part_1.len() + 2)];
+    program.extend(part_1.iter().map(|line| line.to_string()));
+    program.push(format!("jmp +{}", part_2.len() + 1));
+    program.extend(part_2.iter().map(|line| line.to_string()));
+    program.extend(
+        [
+            "jio a, +8",
+            "inc b",
+            "jie a, +4",
+            "tpl a",
+            "inc a",
+            "jmp +2",
+            "hlf a",
+            "jmp -7",
+        ]
+        .map(String::from),
+    );
+    program.join("\n")
+}
+
+#[derive(Clone)]
+pub struct Day23;
+
+impl Solution for Day23 {
+    type Input<'a> = Vec<Instruction<'a>>;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    const PUZZLE_SIZE: usize = 15;
+
+    fn parse<'a>(&self, input: &'a PuzzleInput) -> Result<Self::Input<'a>, Error> {
+        input.lines().map(Instruction::from_str).collect()
+    }
+
+    fn part_1(&self, instructions: &Vec<Instruction>) -> usize {
+        let mut program = Program::new(instructions);
+        program.run();
+        program.registers.entry("b").or_default().value
+    }
+
+    fn part_2(&self, instructions: &Vec<Instruction>) -> usize {
+        let mut program = Program::new(instructions);
+        program.registers.entry("a").or_default().value = 1;
+        program.run();
+        program.registers.entry("b").or_default().value
+    }
+
+    fn generate(&self, rng: &mut Rng, size: usize) -> PuzzleInput {
+        PuzzleInput::new(generate_program(rng, size))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::answers;
+
+    fn program<'a>(lines: &[&'a str]) -> Program<'a> {
+        let instructions: Result<Vec<_>, _> = lines
+            .iter()
+            .map(|line| Instruction::from_str(line))
+            .collect();
+        Program::new(&instructions.unwrap())
+    }
+
+    #[test]
+    fn example_program() {
+        let mut program = program(&["inc a", "jio a, +2", "tpl a", "inc a"]);
+        program.run();
+        assert_eq!(program.registers["a"].value, 2);
+    }
+
+    #[test]
+    fn even_jump_and_halving() {
+        let mut program = program(&["jie a, +2", "inc b", "tpl b", "inc b", "hlf b"]);
+        program.run();
+        assert_eq!(program.registers["b"].value, 0);
+    }
+
+    #[test]
+    fn generated_programs_halt() {
+        for seed in 0..5 {
+            let input = Day23.generate(&mut Rng::new(seed), 20);
+            let instructions = Day23.parse(&input).unwrap();
+            Day23.part_1(&instructions);
+            Day23.part_2(&instructions);
+        }
+    }
+
+    #[test]
+    fn answers_match() {
+        assert_eq!(answers(&Day23, 23), ["170", "247"]);
+    }
+}
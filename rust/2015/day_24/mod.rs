@@ -0,0 +1,157 @@
+use crate::{
+    combinatorics::{
+        can_partition_equally, count_subsets_with_sum_by_size, subsets_with_sum_and_size,
+    },
+    input::PuzzleInput,
+    parse::parse_number,
+    random::Rng,
+    Error, Found, Solution,
+};
+
+fn quantum_entanglement(group: &[usize]) -> u128 {
+    group.iter().map(|&package| package as u128).product()
+}
+
+fn find_quantum_entanglement(packages: &[usize], nr_groups: usize) -> Found<u128> {
+    const NO_SPLIT: &str = "the packages can't be split into groups of the same weight";
+    let total_weight = packages.iter().sum::<usize>();
+    if !total_weight.is_multiple_of(nr_groups) {
+        return Found::Nothing(NO_SPLIT);
+    }
+    let target_weight = total_weight / nr_groups;
+
+    // Only the smallest first groups matter, so they are tried one size at a
+    // time and never all collected
+    let sizes = count_subsets_with_sum_by_size(packages, target_weight);
+    let best = (1..sizes.len())
+        .filter(|&size| sizes[size] != Some(0))
+        .find_map(|size| {
+            subsets_with_sum_and_size(packages, target_weight, size)
+                .filter(|first_group| {
+                    let remaining_packages: Vec<usize> = packages
+                        .iter()
+                        .copied()
+                        .filter(|package| !first_group.contains(package))
+                        .collect();
+                    can_partition_equally(&remaining_packages, nr_groups - 1)
+                })
+                .map(|first_group| quantum_entanglement(&first_group))
+                .min()
+        });
+    Found::from_option(best, NO_SPLIT)
+}
+
+/// `size` different package weights, at least two for each of twelve piles of
+/// the same weight, in increasing order. Four piles make a third of them
+/// and three piles make a quarter, so both parts have a way to split them.
+fn generate_packages(rng: &mut Rng, size: usize) -> Vec<usize> {
+    const PILES: usize = 12;
+    let size = size.max(2 * PILES);
+    let pile_weight = rng.range(3 * size..=5 * size);
+
+    let mut packages = Vec::with_capacity(size);
+    for pile in 0..PILES {
+        let count = size / PILES + usize::from(pile < size % PILES);
+        loop {
+            // All but the last package are light enough to leave room for it
+            let mut pile_packages: Vec<usize> = (1..count)
+                .map(|_| rng.range(1..=2 * pile_weight / count))
+                .collect();
+            let rest = pile_weight.checked_sub(pile_packages.iter().sum());
+            pile_packages.extend(rest);
+            let mut seen = packages.clone();
+            if pile_packages.len() == count
+                && pile_packages.iter().all(|&package| {
+                    let fresh = package > 0 && !seen.contains(&package);
+                    seen.push(package);
+                    fresh
+                })
+            {
+                packages = seen;
+                break;
+            }
+        }
+    }
+    packages.sort();
+    packages
+}
+
+#[derive(Clone)]
+pub struct Day24;
+
+impl Solution for Day24 {
+    type Input<'a> = Vec<usize>;
+    type Output1 = Found<u128>;
+    type Output2 = Found<u128>;
+
+    const PUZZLE_SIZE: usize = 28;
+
+    fn parse<'a>(&self, input: &'a PuzzleInput) -> Result<Self::Input<'a>, Error> {
+        input
+            .lines()
+            .map(|line| parse_number(line, "package weight"))
+            .collect()
+    }
+
+    fn part_1(&self, packages: &Vec<usize>) -> Found<u128> {
+        find_quantum_entanglement(packages, 3)
+    }
+
+    fn part_2(&self, packages: &Vec<usize>) -> Found<u128> {
+        find_quantum_entanglement(packages, 4)
+    }
+
+    fn generate(&self, rng: &mut Rng, size: usize) -> PuzzleInput {
+        let packages: Vec<String> = generate_packages(rng, size)
+            .iter()
+            .map(usize::to_string)
+            .collect();
+        PuzzleInput::new(packages.join("\n"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::answers;
+
+    const EXAMPLE: [usize; 10] = [1, 2, 3, 4, 5, 7, 8, 9, 10, 11];
+
+    #[test]
+    fn three_groups() {
+        assert_eq!(find_quantum_entanglement(&EXAMPLE, 3), Found::Answer(99));
+    }
+
+    #[test]
+    fn four_groups() {
+        assert_eq!(find_quantum_entanglement(&EXAMPLE, 4), Found::Answer(44));
+    }
+
+    #[test]
+    fn uneven_packages() {
+        assert!(matches!(
+            find_quantum_entanglement(&[1, 2, 4], 3),
+            Found::Nothing(_)
+        ));
+        assert!(matches!(
+            find_quantum_entanglement(&[1, 1, 4], 3),
+            Found::Nothing(_)
+        ));
+    }
+
+    #[test]
+    fn generated_packages_split_both_ways() {
+        for seed in 0..3 {
+            let packages = generate_packages(&mut Rng::new(seed), 28);
+            assert_eq!(packages.len(), 28);
+            assert!(packages.windows(2).all(|pair| pair[0] < pair[1]));
+            assert!(can_partition_equally(&packages, 3));
+            assert!(can_partition_equally(&packages, 4));
+        }
+    }
+
+    #[test]
+    fn answers_match() {
+        assert_eq!(answers(&Day24, 24), ["11266889531", "77387711"]);
+    }
+}
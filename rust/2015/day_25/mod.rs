@@ -0,0 +1,158 @@
+use crate::{
+    grid::Point,
+    input::PuzzleInput,
+    parse::{parse_number, Pattern},
+    random::Rng,
+    Error, Solution,
+};
+
+const INITIAL_VALUE: u64 = 20151125;
+const MULTIPLYER: u64 = 252533;
+const DIVIDER: u64 = 33554393;
+
+#[cfg(test)]
+fn calculate_next_value(previous_value: u64) -> u64 {
+    (previous_value * MULTIPLYER) % DIVIDER
+}
+
+/// How many codes come before `position` and including it. The manual fills its
+/// grid one diagonal at a time, each running from the first column up to the
+/// first row, with row 1 and column 1 at the point (1, 1).
+fn code_number(position: Point) -> u64 {
+    let (row, column) = (position.y as u64, position.x as u64);
+    let diagonal_nr = row + column - 1;
+    ((diagonal_nr * (diagonal_nr - 1)) / 2) + column
+}
+
+/// `base` to the power `exponent`, modulo `modulus`, by repeated squaring.
+fn pow_mod(mut base: u64, mut exponent: u64, modulus: u64) -> u64 {
+    let mut result = 1;
+    base %= modulus;
+    while exponent > 0 {
+        if exponent & 1 == 1 {
+            result = (result * base) % modulus;
+        }
+        base = (base * base) % modulus;
+        exponent >>= 1;
+    }
+    result
+}
+
+/// Each code is the previous one times [`MULTIPLYER`], so the code at
+/// `position` is the first one times a power of it.
+fn coord_value(position: Point) -> u64 {
+    let number_of_calculations = code_number(position);
+    (INITIAL_VALUE * pow_mod(MULTIPLYER, number_of_calculations - 1, DIVIDER)) % DIVIDER
+}
+
+/// [`coord_value`] one code at a time, to check the shortcut against.
+#[cfg(test)]
+fn coord_value_by_iteration(position: Point) -> u64 {
+    let number_of_calculations = code_number(position);
+
+    let mut value = INITIAL_VALUE;
+    for _ in 2..=number_of_calculations {
+        value = calculate_next_value(value);
+    }
+    value
+}
+
+const CODE_POSITION: Pattern = Pattern::new(
+    "To continue, please consult the code grid in the manual.  Enter the code at row {row}, column {column}.",
+);
+
+fn parse_input(input: &str) -> Result<Point, Error> {
+    let (row, column) = CODE_POSITION.parse(input.trim())?;
+    Ok(Point::new(
+        parse_coordinate(column, "column")?,
+        parse_coordinate(row, "row")?,
+    ))
+}
+
+/// Rows and columns are counted from 1.
+fn parse_coordinate(text: &str, what: &str) -> Result<i32, Error> {
+    let coordinate = parse_number(text, what)?;
+    if coordinate < 1 {
+        return Err(Error::at(text, format!("The first {what} is 1")));
+    }
+    Ok(coordinate)
+}
+
+#[derive(Clone)]
+pub struct Day25;
+
+impl Solution for Day25 {
+    type Input<'a> = Point;
+    type Output1 = u64;
+    /// Day 25 has no second puzzle; the last star is awarded for finishing the others.
+    type Output2 = &'static str;
+
+    const PUZZLE_SIZE: usize = 3_000;
+
+    fn parse<'a>(&self, input: &'a PuzzleInput) -> Result<Self::Input<'a>, Error> {
+        parse_input(input)
+    }
+
+    fn part_1(&self, &position: &Point) -> u64 {
+        coord_value(position)
+    }
+
+    fn part_2(&self, _: &Point) -> &'static str {
+        "Merry Christmas!"
+    }
+
+    /// A row and a column of at most `size`.
+    fn generate(&self, rng: &mut Rng, size: usize) -> PuzzleInput {
+        let size = size.max(1);
+        PuzzleInput::new(format!(
+            "To continue, please consult the code grid in the manual.  Enter the code at row {}, column {}.",
+            rng.range(1..=size),
+            rng.range(1..=size)
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{check::Checker, testing::answers};
+
+    #[test]
+    fn code_grid() {
+        // Points are (column, row)
+        assert_eq!(coord_value(Point::new(1, 1)), 20151125);
+        assert_eq!(coord_value(Point::new(1, 2)), 31916031);
+        assert_eq!(coord_value(Point::new(2, 1)), 18749137);
+        assert_eq!(coord_value(Point::new(6, 6)), 27995004);
+    }
+
+    #[test]
+    fn powers_match_iteration() {
+        Checker::new(&Day25)
+            .max_size(1_000)
+            .differential(
+                |&position| coord_value_by_iteration(position),
+                |&position| coord_value(position),
+            )
+            .unwrap();
+    }
+
+    #[test]
+    fn input_position() {
+        let input = "To continue, please consult the code grid in the manual.  \
+                     Enter the code at row 2981, column 3075.";
+        assert_eq!(parse_input(input), Ok(Point::new(3075, 2981)));
+
+        let input = "To continue, please consult the code grid in the manual.  \
+                     Enter the code at row 0, column 3.";
+        assert_eq!(
+            parse_input(input).unwrap_err().to_string(),
+            "The first row is 1: \"0\""
+        );
+    }
+
+    #[test]
+    fn answers_match() {
+        assert_eq!(answers(&Day25, 25), ["8997277", "Merry Christmas!"]);
+    }
+}
@@ -0,0 +1,62 @@
+//! Draws day 3's deliveries: `visits.pgm` and `visits.ppm` shade each house by
+//! how many presents it got, in grey and in colour, and `routes.svg` traces
+//! every agent's route.
+//!
+//! ```text
+//! cargo run --release -p advent_of_code_2015 --example day_03_images -- <dir> [turns] [input]
+//! ```
+//!
+//! `turns` shares the directions out as the `turns` parameter does, and is
+//! `round-robin:2` by default; `input` is a path, or `-` for stdin, instead of
+//! our own input.
+
+use advent_of_code_2015::{
+    day_03::{Day03, Delivery, TurnPolicy},
+    input::{InputSource, PuzzleInput},
+    Error, Solution,
+};
+use std::{env, fs, path::PathBuf, process::ExitCode};
+
+const USAGE: &str = "Usage: day_03_images <dir> [turns] [input]";
+
+fn run(args: &[String]) -> Result<(), Error> {
+    let [dir, rest @ ..] = args else {
+        return Err(Error::new(USAGE));
+    };
+    let (turns, source) = match rest {
+        [] => ("round-robin:2", InputSource::Default),
+        [turns] => (turns.as_str(), InputSource::Default),
+        [turns, input] => (turns.as_str(), InputSource::from_arg(input)),
+        _ => return Err(Error::new(USAGE)),
+    };
+    let policy = TurnPolicy::parse(turns)?;
+    let input = PuzzleInput::load(2015, 3, Day03::INPUT_FILES, &source)?;
+    let directions = Day03::PUZZLE.parse(&input).map_err(|e| input.locate(e))?;
+    let delivery = Delivery::new(&directions, &policy);
+
+    let dir = PathBuf::from(dir);
+    fs::create_dir_all(&dir)
+        .map_err(|e| Error::new(format!("Could not create {}: {e}", dir.display())))?;
+    for (name, image) in [
+        ("visits.pgm", delivery.to_pgm()),
+        ("visits.ppm", delivery.to_ppm()),
+        ("routes.svg", delivery.to_svg()),
+    ] {
+        let path = dir.join(name);
+        fs::write(&path, image)
+            .map_err(|e| Error::new(format!("Could not write {}: {e}", path.display())))?;
+        println!("{}", path.display());
+    }
+    Ok(())
+}
+
+fn main() -> ExitCode {
+    let args: Vec<String> = env::args().skip(1).collect();
+    match run(&args) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("{e}");
+            ExitCode::FAILURE
+        }
+    }
+}
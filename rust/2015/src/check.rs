@@ -0,0 +1,270 @@
+//! Property-based and differential checks of a day on its generated inputs.
+//!
+//! A [`Checker`] runs a property, or two implementations that should agree, on
+//! inputs from the day's generator, starting small and growing to a maximum
+//! size. The first failing input is then minimised: first by looking for a
+//! smaller generated input that fails too, then by deleting lines and
+//! characters for as long as the input still parses and still fails.
+
+use crate::{input::PuzzleInput, random::Rng, Solution};
+use std::{
+    any::Any,
+    fmt::{self, Debug, Display},
+    panic::{self, AssertUnwindSafe},
+};
+
+pub struct Checker<'s, S> {
+    solution: &'s S,
+    cases: u64,
+    max_size: usize,
+    seed: u64,
+}
+
+/// A failing input, as small as the checker could make it.
+pub struct Counterexample {
+    /// The seed and size of the generated input that failed first.
+    pub seed: u64,
+    pub size: usize,
+    pub input: PuzzleInput,
+    pub failure: String,
+}
+
+impl<'s, S: Solution> Checker<'s, S> {
+    /// A checker running 100 cases up to the size of the puzzle's input.
+    pub fn new(solution: &'s S) -> Self {
+        Checker {
+            solution,
+            cases: 100,
+            max_size: S::PUZZLE_SIZE,
+            seed: 0,
+        }
+    }
+
+    pub fn cases(mut self, cases: u64) -> Self {
+        self.cases = cases;
+        self
+    }
+
+    pub fn max_size(mut self, max_size: usize) -> Self {
+        self.max_size = max_size;
+        self
+    }
+
+    /// The seed of the first case; the others count up from it.
+    pub fn seed(mut self, seed: u64) -> Self {
+        self.seed = seed;
+        self
+    }
+
+    /// Checks that `property` holds for every input, where `Err` explains how
+    /// it didn't. A panic counts as a failure too. Inputs the day can't parse
+    /// are skipped.
+    pub fn property(
+        &self,
+        property: impl Fn(&S::Input<'_>) -> Result<(), String>,
+    ) -> Result<(), Counterexample> {
+        let failure = |input: &PuzzleInput| {
+            let parsed = self.solution.parse(input).ok()?;
+            match panic::catch_unwind(AssertUnwindSafe(|| property(&parsed))) {
+                Ok(result) => result.err(),
+                Err(payload) => Some(format!("Panicked: {}", panic_message(&*payload))),
+            }
+        };
+
+        for case in 0..self.cases {
+            let seed = self.seed + case;
+            let size = self.size(case);
+            let input = self.generate(seed, size);
+            if failure(&input).is_some() {
+                let input = self.minimise(seed, size, input, failure);
+                let failure = failure(&input).expect("Minimised inputs still fail");
+                return Err(Counterexample {
+                    seed,
+                    size,
+                    input,
+                    failure,
+                });
+            }
+        }
+        Ok(())
+    }
+
+    /// Checks that `candidate` gives the same result as `reference` for every
+    /// input. Inputs that make the reference panic have no right answer and
+    /// are skipped.
+    pub fn differential<T: PartialEq + Debug>(
+        &self,
+        reference: impl Fn(&S::Input<'_>) -> T,
+        candidate: impl Fn(&S::Input<'_>) -> T,
+    ) -> Result<(), Counterexample> {
+        self.property(|input| {
+            let Ok(expected) = panic::catch_unwind(AssertUnwindSafe(|| reference(input))) else {
+                return Ok(());
+            };
+            let actual = candidate(input);
+            if actual == expected {
+                Ok(())
+            } else {
+                Err(format!("Expected {expected:?}, got {actual:?}"))
+            }
+        })
+    }
+
+    /// Sizes grow quadratically from 1 to the maximum, so most cases are
+    /// small, where the edge cases are.
+    fn size(&self, case: u64) -> usize {
+        let last = self.cases.saturating_sub(1).max(1) as u128;
+        let size =
+            1 + (self.max_size.saturating_sub(1) as u128 * (case as u128).pow(2)) / last.pow(2);
+        size as usize
+    }
+
+    fn generate(&self, seed: u64, size: usize) -> PuzzleInput {
+        self.solution.generate(&mut Rng::new(seed), size)
+    }
+
+    fn minimise(
+        &self,
+        seed: u64,
+        size: usize,
+        input: PuzzleInput,
+        failure: impl Fn(&PuzzleInput) -> Option<String>,
+    ) -> PuzzleInput {
+        let fails = |input: &PuzzleInput| failure(input).is_some();
+
+        let mut input = (0..usize::BITS)
+            .map(|power| 1 << power)
+            .take_while(|&smaller| smaller < size)
+            .map(|smaller| self.generate(seed, smaller))
+            .find(|smaller| fails(smaller))
+            .unwrap_or(input);
+
+        let lines: Vec<String> = input.text().lines().map(String::from).collect();
+        let lines = remove_pieces(lines, |lines| fails(&with_text(&input, &lines.join("\n"))));
+        input = with_text(&input, &lines.join("\n"));
+
+        let chars: Vec<String> = input.text().chars().map(String::from).collect();
+        let chars = remove_pieces(chars, |chars| fails(&with_text(&input, &chars.concat())));
+        with_text(&input, &chars.concat())
+    }
+}
+
+/// Deletes runs of `pieces`, halving the length of the runs it tries, for as
+/// long as what is left keeps `fails` true.
+fn remove_pieces(mut pieces: Vec<String>, fails: impl Fn(&[String]) -> bool) -> Vec<String> {
+    let mut run = pieces.len() / 2;
+    while run > 0 {
+        let mut start = 0;
+        while start < pieces.len() {
+            let end = (start + run).min(pieces.len());
+            let remaining = [&pieces[..start], &pieces[end..]].concat();
+            if fails(&remaining) {
+                pieces = remaining;
+            } else {
+                start = end;
+            }
+        }
+        run = run.min(pieces.len()) / 2;
+    }
+    pieces
+}
+
+/// `input` with the text of its primary file replaced.
+fn with_text(input: &PuzzleInput, text: &str) -> PuzzleInput {
+    let mut files = input.files();
+    let (name, _) = files.next().expect("Inputs have a primary file");
+    files.fold(PuzzleInput::named(name, text), |input, (name, text)| {
+        input.with_file(name, text)
+    })
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> &str {
+    payload
+        .downcast_ref::<&str>()
+        .copied()
+        .or_else(|| payload.downcast_ref::<String>().map(String::as_str))
+        .unwrap_or("<non-string payload>")
+}
+
+impl Display for Counterexample {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "{} (generated with seed {} and size {}, then minimised)",
+            self.failure, self.seed, self.size
+        )?;
+        for (name, text) in self.input.files() {
+            writeln!(f, "==> {name}.txt <==\n{text}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Shows the same report as [`Display`], so a failing `unwrap` in a test
+/// prints the input.
+impl Debug for Counterexample {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::day_10::{Config, Day10};
+
+    const FIVE_STEPS: Day10 = Day10 {
+        config: Config {
+            part_1_steps: 5,
+            part_2_steps: 5,
+        },
+    };
+
+    /// Five look-and-say steps spelled out one string at a time.
+    fn look_and_say(input: &&str) -> usize {
+        (0..5)
+            .fold(input.to_string(), |string, _| {
+                string
+                    .as_bytes()
+                    .chunk_by(|a, b| a == b)
+                    .map(|run| format!("{}{}", run.len(), char::from(run[0])))
+                    .collect()
+            })
+            .len()
+    }
+
+    #[test]
+    fn passes_when_implementations_agree() {
+        Checker::new(&FIVE_STEPS)
+            .cases(20)
+            .max_size(200)
+            .differential(look_and_say, |input| FIVE_STEPS.part_1(input))
+            .unwrap();
+    }
+
+    #[test]
+    fn minimises_a_disagreement() {
+        let miscounts_threes =
+            |input: &&str| FIVE_STEPS.part_1(input) + usize::from(input.contains("33"));
+        let counterexample = Checker::new(&FIVE_STEPS)
+            .max_size(200)
+            .differential(look_and_say, miscounts_threes)
+            .unwrap_err();
+        assert_eq!(counterexample.input.text(), "33");
+        assert_eq!(counterexample.failure, "Expected 12, got 13");
+    }
+
+    #[test]
+    fn panics_are_failures() {
+        let counterexample = Checker::new(&FIVE_STEPS)
+            .max_size(200)
+            .property(|input| {
+                let length = FIVE_STEPS.part_1(input);
+                assert!(length < 20, "grew to {length} digits");
+                Ok(())
+            })
+            .unwrap_err();
+        assert_eq!(counterexample.input.text(), "23");
+        assert_eq!(counterexample.failure, "Panicked: grew to 20 digits");
+    }
+}
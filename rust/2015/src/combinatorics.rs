@@ -0,0 +1,383 @@
+//! Enumerating and counting the ways to pick or split numbers: subsets with a
+//! given sum, splits into groups of equal sum, and compositions of a total.
+//!
+//! The iterators are lazy, so a day can stop at the first answer it likes. The
+//! `count_*` functions use dynamic programming or closed forms instead of
+//! visiting every combination.
+
+use std::iter;
+
+/// Every subset of `items` adding up to `target`, as the values picked in the
+/// order they appear. Equal values at different positions are different picks.
+pub fn subsets_with_sum(items: &[usize], target: usize) -> SubsetsWithSum {
+    SubsetsWithSum {
+        indices: SubsetIndices::new(items.to_vec(), target),
+    }
+}
+
+/// Like [`subsets_with_sum`], but only the subsets of `size` items, without
+/// looking at any larger ones.
+pub fn subsets_with_sum_and_size(items: &[usize], target: usize, size: usize) -> SubsetsWithSum {
+    SubsetsWithSum {
+        indices: SubsetIndices {
+            min_len: size,
+            max_len: size,
+            ..SubsetIndices::new(items.to_vec(), target)
+        },
+    }
+}
+
+pub struct SubsetsWithSum {
+    indices: SubsetIndices,
+}
+
+impl Iterator for SubsetsWithSum {
+    type Item = Vec<usize>;
+
+    fn next(&mut self) -> Option<Vec<usize>> {
+        let indices = self.indices.next()?;
+        Some(indices.iter().map(|&i| self.indices.items[i]).collect())
+    }
+}
+
+/// Depth-first search over the positions of `items`, yielding the positions of
+/// each subset that reaches `target` as soon as it is found.
+struct SubsetIndices {
+    items: Vec<usize>,
+    target: usize,
+    chosen: Vec<usize>,
+    sum: usize,
+    /// The next position to try adding.
+    next: usize,
+    /// The empty subset only matches a target of zero, and has to be reported
+    /// before the search starts adding items.
+    started: bool,
+    /// Subsets with fewer items are skipped, and no more are ever added.
+    min_len: usize,
+    max_len: usize,
+}
+
+impl SubsetIndices {
+    fn new(items: Vec<usize>, target: usize) -> Self {
+        SubsetIndices {
+            items,
+            target,
+            chosen: Vec::new(),
+            sum: 0,
+            next: 0,
+            started: false,
+            min_len: 0,
+            max_len: usize::MAX,
+        }
+    }
+}
+
+impl Iterator for SubsetIndices {
+    type Item = Vec<usize>;
+
+    fn next(&mut self) -> Option<Vec<usize>> {
+        if !self.started {
+            self.started = true;
+            if self.target == 0 && self.min_len == 0 {
+                return Some(Vec::new());
+            }
+        }
+
+        loop {
+            if let Some(&item) = self.items.get(self.next) {
+                let index = self.next;
+                self.next += 1;
+                if self.sum + item <= self.target && self.chosen.len() < self.max_len {
+                    self.chosen.push(index);
+                    self.sum += item;
+                    if self.sum == self.target && self.chosen.len() >= self.min_len {
+                        return Some(self.chosen.clone());
+                    }
+                }
+            } else {
+                let last = self.chosen.pop()?;
+                self.sum -= self.items[last];
+                self.next = last + 1;
+            }
+        }
+    }
+}
+
+/// How many subsets of `items` add up to `target`, or `None` if there are
+/// more than fit in a u64.
+pub fn count_subsets_with_sum(items: &[usize], target: usize) -> Option<u64> {
+    count_subsets_with_sum_by_size(items, target)
+        .into_iter()
+        .try_fold(0u64, |total, count| total.checked_add(count?))
+}
+
+/// How many subsets of `items` add up to `target`, indexed by how many items
+/// they use. A count is `None` if it doesn't fit in a u64, which still means
+/// there are some.
+pub fn count_subsets_with_sum_by_size(items: &[usize], target: usize) -> Vec<Option<u64>> {
+    // ways[size][sum]
+    let mut ways = vec![vec![Some(0u64); target + 1]; items.len() + 1];
+    ways[0][0] = Some(1);
+    for (seen, &item) in items.iter().enumerate() {
+        // Too big to be in any of the subsets
+        let Some(room) = target.checked_sub(item) else {
+            continue;
+        };
+        for size in (0..=seen).rev() {
+            for sum in (0..=room).rev() {
+                let (with, without) = (ways[size][sum], ways[size + 1][sum + item]);
+                ways[size + 1][sum + item] = with.zip(without).and_then(|(a, b)| a.checked_add(b));
+            }
+        }
+    }
+    ways.into_iter().map(|sums| sums[target]).collect()
+}
+
+/// Every way to split `items` into `groups` groups with the same sum. A split
+/// doesn't come out again with its groups reordered: they are ordered by the
+/// position of their first item. Equal values at different positions are still
+/// different items.
+pub fn equal_sum_partitions(
+    items: &[usize],
+    groups: usize,
+) -> impl Iterator<Item = Vec<Vec<usize>>> {
+    let total: usize = items.iter().sum();
+    let target = match groups {
+        0 => Some(0),
+        groups => total.is_multiple_of(groups).then(|| total / groups),
+    };
+    let items = items.to_vec();
+    target
+        .into_iter()
+        .flat_map(move |target| partitions_with_sum(items.clone(), groups, target))
+}
+
+/// How many splits [`equal_sum_partitions`] gives, without building them: the
+/// group before last is picked one subset at a time, and the rest of its
+/// items then make up the last group. `None` if there are more than fit in a
+/// u64.
+pub fn count_equal_sum_partitions(items: &[usize], groups: usize) -> Option<u64> {
+    let total: usize = items.iter().sum();
+    match groups {
+        0 => Some(u64::from(items.is_empty())),
+        groups if total.is_multiple_of(groups) => {
+            count_partitions_with_sum(items, groups, total / groups)
+        }
+        _ => Some(0),
+    }
+}
+
+fn count_partitions_with_sum(items: &[usize], groups: usize, target: usize) -> Option<u64> {
+    let Some((&first, rest)) = items.split_first() else {
+        return Some(u64::from(groups == 0));
+    };
+    let Some(rest_of_group) = target.checked_sub(first) else {
+        return Some(0);
+    };
+    match groups {
+        0 => Some(0),
+        1 => Some(u64::from(items.iter().sum::<usize>() == target)),
+        // Whatever the first group leaves has the right sum for the second
+        2 => count_subsets_with_sum(rest, rest_of_group),
+        groups => {
+            SubsetIndices::new(rest.to_vec(), rest_of_group).try_fold(0u64, |count, chosen| {
+                let remaining: Vec<usize> = rest
+                    .iter()
+                    .enumerate()
+                    .filter(|(i, _)| !chosen.contains(i))
+                    .map(|(_, &item)| item)
+                    .collect();
+                count.checked_add(count_partitions_with_sum(&remaining, groups - 1, target)?)
+            })
+        }
+    }
+}
+
+/// Whether `items` can be split into `groups` groups with the same sum.
+pub fn can_partition_equally(items: &[usize], groups: usize) -> bool {
+    equal_sum_partitions(items, groups).next().is_some()
+}
+
+fn partitions_with_sum(
+    items: Vec<usize>,
+    groups: usize,
+    target: usize,
+) -> Box<dyn Iterator<Item = Vec<Vec<usize>>>> {
+    let Some((&first, rest)) = items.split_first() else {
+        return Box::new((groups == 0).then(Vec::new).into_iter());
+    };
+    if groups <= 1 {
+        let whole = groups == 1 && items.iter().sum::<usize>() == target;
+        return Box::new(whole.then(|| vec![items]).into_iter());
+    }
+    let Some(rest_of_group) = target.checked_sub(first) else {
+        return Box::new(iter::empty());
+    };
+
+    // The first item has to go somewhere; putting it in the first group keeps
+    // the same split from coming out again with its groups reordered
+    let rest = rest.to_vec();
+    Box::new(
+        SubsetIndices::new(rest.clone(), rest_of_group).flat_map(move |chosen| {
+            let group: Vec<usize> = iter::once(first)
+                .chain(chosen.iter().map(|&i| rest[i]))
+                .collect();
+            let remaining = rest
+                .iter()
+                .enumerate()
+                .filter(|(i, _)| !chosen.contains(i))
+                .map(|(_, &item)| item)
+                .collect();
+            partitions_with_sum(remaining, groups - 1, target).map(move |mut partition| {
+                partition.insert(0, group.clone());
+                partition
+            })
+        }),
+    )
+}
+
+/// Every way to write `total` as an ordered sum of `parts` numbers, zeroes
+/// included, in lexicographic order.
+pub fn compositions(total: usize, parts: usize) -> Compositions {
+    let first = match parts {
+        0 => (total == 0).then(Vec::new),
+        parts => {
+            let mut first = vec![0; parts];
+            first[parts - 1] = total;
+            Some(first)
+        }
+    };
+    Compositions { next: first }
+}
+
+pub struct Compositions {
+    next: Option<Vec<usize>>,
+}
+
+impl Iterator for Compositions {
+    type Item = Vec<usize>;
+
+    fn next(&mut self) -> Option<Vec<usize>> {
+        let current = self.next.take()?;
+
+        // Move one unit from the tail to the rightmost part that has a
+        // non-empty tail after it, and gather the rest of the tail at the end
+        let mut following = current.clone();
+        let mut tail = 0;
+        for i in (0..following.len()).rev() {
+            if tail > 0 {
+                following[i] += 1;
+                following[i + 1..].fill(0);
+                *following.last_mut()? = tail - 1;
+                self.next = Some(following);
+                break;
+            }
+            tail += following[i];
+        }
+        Some(current)
+    }
+}
+
+/// How many ways `total` can be written as an ordered sum of `parts` numbers,
+/// zeroes included, or `None` if there are more than fit in a u64.
+pub fn count_compositions(total: usize, parts: usize) -> Option<u64> {
+    match parts {
+        0 => Some(u64::from(total == 0)),
+        parts => binomial(
+            (total as u128).checked_add(parts as u128 - 1)?,
+            parts as u128 - 1,
+        ),
+    }
+}
+
+/// `n` choose `k`, or `None` if it doesn't fit in a u64. Each partial product
+/// is itself a binomial coefficient no larger than the result, so multiplying
+/// it by at most `n` fits in a u128 whenever the result fits in a u64.
+fn binomial(n: u128, k: u128) -> Option<u64> {
+    let k = k.min(n - k);
+    let result = (0..k).try_fold(1u128, |result, i| {
+        let result = result.checked_mul(n - i)? / (i + 1);
+        (result <= u64::MAX as u128).then_some(result)
+    })?;
+    u64::try_from(result).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CONTAINERS: [usize; 5] = [20, 15, 10, 5, 5];
+
+    #[test]
+    fn subsets() {
+        let subsets: Vec<_> = subsets_with_sum(&CONTAINERS, 25).collect();
+        assert_eq!(
+            subsets,
+            [vec![20, 5], vec![20, 5], vec![15, 10], vec![15, 5, 5]]
+        );
+        assert_eq!(count_subsets_with_sum(&CONTAINERS, 25), Some(4));
+        assert_eq!(
+            count_subsets_with_sum_by_size(&CONTAINERS, 25),
+            [0, 0, 3, 1, 0, 0].map(Some)
+        );
+        assert_eq!(count_subsets_with_sum(&CONTAINERS, 12), Some(0));
+
+        // C(40, 35) ways to pick 35 of 40 ones, each with any of 2^100 sets
+        // of zeroes, which is more than a u64 holds
+        let bits: Vec<usize> = (0..140).map(|i| usize::from(i < 40)).collect();
+        let by_size = count_subsets_with_sum_by_size(&bits, 35);
+        assert_eq!(count_subsets_with_sum(&bits, 35), None);
+        assert_eq!(by_size[34], Some(0));
+        assert_eq!(by_size[35], Some(658_008));
+        assert_eq!(by_size[36], Some(65_800_800));
+        assert_eq!(by_size[85], None);
+        assert_eq!(
+            subsets_with_sum_and_size(&CONTAINERS, 25, 2).collect::<Vec<_>>(),
+            [vec![20, 5], vec![20, 5], vec![15, 10]]
+        );
+        assert_eq!(subsets_with_sum_and_size(&CONTAINERS, 25, 4).count(), 0);
+        assert_eq!(
+            subsets_with_sum(&[1, 2], 0).collect::<Vec<_>>(),
+            [Vec::<usize>::new()]
+        );
+    }
+
+    #[test]
+    fn partitions() {
+        let items = [1, 2, 3, 4, 5, 7, 8, 9, 10, 11];
+        let first = equal_sum_partitions(&items, 3).next().unwrap();
+        assert!(first.iter().all(|group| group.iter().sum::<usize>() == 20));
+        assert_eq!(first.concat().len(), items.len());
+        assert_eq!(
+            equal_sum_partitions(&[1, 1, 2, 2], 2).collect::<Vec<_>>(),
+            [vec![vec![1, 2], vec![1, 2]], vec![vec![1, 2], vec![1, 2]]]
+        );
+        for groups in 0..5 {
+            assert_eq!(
+                count_equal_sum_partitions(&items, groups),
+                Some(equal_sum_partitions(&items, groups).count() as u64),
+                "{groups} groups"
+            );
+        }
+        assert_eq!(count_equal_sum_partitions(&[1, 1, 2, 2], 2), Some(2));
+        assert!(!can_partition_equally(&[1, 2, 4], 2));
+        assert!(can_partition_equally(&[], 0));
+    }
+
+    #[test]
+    fn compositions_of_a_total() {
+        assert_eq!(
+            compositions(2, 2).collect::<Vec<_>>(),
+            [vec![0, 2], vec![1, 1], vec![2, 0]]
+        );
+        assert_eq!(
+            Some(compositions(100, 4).count() as u64),
+            count_compositions(100, 4)
+        );
+        assert_eq!(count_compositions(100, 4), Some(176_851));
+        assert_eq!(count_compositions(34, 34), Some(14_226_520_737_620_288_370));
+        assert_eq!(count_compositions(35, 35), None);
+        assert_eq!(compositions(3, 0).count(), 0);
+        assert_eq!(compositions(0, 0).count(), 1);
+    }
+}
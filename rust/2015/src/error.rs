@@ -0,0 +1,242 @@
+//! The error shared by every day's parser and the input loading around it.
+//!
+//! Parsers report the offending slice of their input with [`Error::at`]. That
+//! slice still points into the [`PuzzleInput`](crate::input::PuzzleInput), so
+//! once the error reaches it the input can work out the day, file, line and
+//! column it came from and show the line in context.
+
+use std::{fmt, ops::Range};
+
+/// Where in a day's input an error was found.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Location {
+    pub file: String,
+    /// 1-based.
+    pub line: usize,
+    /// 1-based, counted in characters.
+    pub column: usize,
+    /// The whole line the error is on.
+    pub source_line: String,
+}
+
+/// Boxed so that results carrying it stay small.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Error(Box<Details>);
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Details {
+    message: String,
+    text: Option<String>,
+    /// Addresses of `text` while it was still borrowed from the input.
+    span: Option<Range<usize>>,
+    /// Line and column reported by a parser that tracks them itself.
+    position: Option<(usize, usize)>,
+    day: Option<(u16, u8)>,
+    location: Option<Location>,
+}
+
+impl Error {
+    pub fn new(message: impl Into<String>) -> Self {
+        Error(Box::new(Details {
+            message: message.into(),
+            text: None,
+            span: None,
+            position: None,
+            day: None,
+            location: None,
+        }))
+    }
+
+    /// An error about `text`, which should be a slice of the day's input.
+    pub fn at(text: &str, message: impl Into<String>) -> Self {
+        let mut error = Error::new(message);
+        let start = text.as_ptr() as usize;
+        error.0.text = Some(text.to_string());
+        error.0.span = Some(start..start + text.len());
+        error
+    }
+
+    /// An error at a 1-based line and column of the day's primary input file.
+    pub fn at_position(line: usize, column: usize, message: impl Into<String>) -> Self {
+        let mut error = Error::new(message);
+        error.0.position = Some((line, column));
+        error
+    }
+
+    pub fn message(&self) -> &str {
+        &self.0.message
+    }
+
+    /// The offending input, if the error is about a particular piece of it.
+    pub fn text(&self) -> Option<&str> {
+        self.0.text.as_deref()
+    }
+
+    /// The year and day whose input this error came from.
+    pub fn day(&self) -> Option<(u16, u8)> {
+        self.0.day
+    }
+
+    pub fn location(&self) -> Option<&Location> {
+        self.0.location.as_ref()
+    }
+
+    pub(crate) fn in_day(mut self, year: u16, day: u8) -> Self {
+        self.0.day.get_or_insert((year, day));
+        self
+    }
+
+    /// Fills in the location from whichever of `files` the offending text was
+    /// borrowed from, falling back to the reported position in the first one.
+    pub(crate) fn locate(mut self, files: &[(String, String)]) -> Self {
+        if self.0.location.is_some() {
+            return self;
+        }
+
+        let from_span = self.0.span.clone().and_then(|span| {
+            files.iter().find_map(|(name, text)| {
+                let start = text.as_ptr() as usize;
+                (start <= span.start && span.end <= start + text.len())
+                    .then(|| (name, text, span.start - start))
+            })
+        });
+        let (name, text, offset) = match (from_span, self.0.position, files.first()) {
+            (Some(found), _, _) => found,
+            (None, Some((line, column)), Some((name, text))) => {
+                let line_start: usize = text
+                    .split_inclusive('\n')
+                    .take(line.saturating_sub(1))
+                    .map(str::len)
+                    .sum();
+                let offset = text[line_start..]
+                    .char_indices()
+                    .nth(column.saturating_sub(1))
+                    .map_or(text.len(), |(i, _)| line_start + i);
+                (name, text, offset)
+            }
+            _ => return self,
+        };
+
+        let line_start = text[..offset].rfind('\n').map_or(0, |i| i + 1);
+        let line_end = text[offset..].find('\n').map_or(text.len(), |i| offset + i);
+        self.0.location = Some(Location {
+            file: name.clone(),
+            line: text[..offset].matches('\n').count() + 1,
+            column: text[line_start..offset].chars().count() + 1,
+            source_line: text[line_start..line_end]
+                .trim_end_matches('\r')
+                .to_string(),
+        });
+        self
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut context = Vec::new();
+        if let Some((year, day)) = self.0.day {
+            context.push(format!("{year} day {day:02}"));
+        }
+        if let Some(location) = &self.0.location {
+            let file = match location.file.as_str() {
+                "input" => String::new(),
+                file => format!("{file}.txt "),
+            };
+            context.push(format!(
+                "{file}line {}, column {}",
+                location.line, location.column
+            ));
+        }
+        if !context.is_empty() {
+            write!(f, "{}: ", context.join(", "))?;
+        }
+        write!(f, "{}", self.0.message)?;
+        if let Some(text) = &self.0.text {
+            write!(f, ": {text:?}")?;
+        }
+
+        if let Some(location) = &self.0.location {
+            let width = self
+                .text()
+                .filter(|text| !text.contains('\n'))
+                .map_or(1, |text| text.chars().count().max(1));
+            write!(
+                f,
+                "\n    {}\n    {}{}",
+                location.source_line,
+                " ".repeat(location.column - 1),
+                "^".repeat(width)
+            )?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for Error {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{day_02::Day02, day_21::Day21, input::PuzzleInput, DynSolution, Part};
+
+    #[test]
+    fn locates_offending_text() {
+        let input = PuzzleInput::new("1x2x3\n4xQx6");
+        let error = Day02.solve(&input, &[Part::One]).unwrap_err();
+        assert_eq!(error.text(), Some("Q"));
+        assert_eq!(
+            error.location(),
+            Some(&Location {
+                file: "input".to_string(),
+                line: 2,
+                column: 3,
+                source_line: "4xQx6".to_string(),
+            })
+        );
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 3: Invalid dimension: \"Q\"\n    4xQx6\n      ^"
+        );
+    }
+
+    #[test]
+    fn locates_text_in_secondary_files() {
+        let input = PuzzleInput::new("Hit Points: 12\nDamage: 7\nArmor: 2")
+            .with_file("shop", "Dagger, 8, 4, 0\nSword, ten, 5, 0");
+        let location = Day21::PUZZLE
+            .solve(&input, &[Part::One])
+            .unwrap_err()
+            .location()
+            .cloned();
+        assert_eq!(
+            location.map(|l| (l.file, l.line, l.column)),
+            Some(("shop".to_string(), 2, 8))
+        );
+    }
+
+    #[test]
+    fn locates_reported_positions() {
+        let error = Error::at_position(2, 2, "Oops")
+            .locate(&[("input".to_string(), "abc\nxyz".to_string())]);
+        let location = error.location().unwrap();
+        assert_eq!(
+            (
+                location.line,
+                location.column,
+                location.source_line.as_str()
+            ),
+            (2, 2, "xyz")
+        );
+    }
+
+    #[test]
+    fn unrelated_text_has_no_location() {
+        let error =
+            Error::at("elsewhere", "Oops").locate(&[("input".to_string(), "abc".to_string())]);
+        assert_eq!(error.location(), None);
+        assert_eq!(
+            error.in_day(2015, 3).to_string(),
+            "2015 day 03: Oops: \"elsewhere\""
+        );
+    }
+}
@@ -0,0 +1,276 @@
+//! Weighted graphs over named nodes, and exact solvers for the best route that
+//! visits every node once.
+//!
+//! Weights live in an adjacency matrix, so a missing edge simply can't be
+//! travelled. The solvers use Held–Karp dynamic programming over subsets of
+//! visited nodes, which is exponential in the node count but far cheaper than
+//! trying every ordering.
+
+use std::{collections::HashMap, ops::Add};
+
+/// The most nodes the route solvers will search. Held–Karp keeps an entry for
+/// every subset of nodes and every last node, so each node past this doubles
+/// a table that is already over 100 MB here.
+pub const MAX_NODES: usize = 18;
+
+/// Whether the best route is the cheapest or the most valuable one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Goal {
+    Min,
+    Max,
+}
+
+impl Goal {
+    fn prefers<W: Ord>(self, candidate: W, current: W) -> bool {
+        match self {
+            Goal::Min => candidate < current,
+            Goal::Max => candidate > current,
+        }
+    }
+}
+
+/// A route through every node and the total weight of the edges it uses.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Route<'a, W> {
+    pub value: W,
+    /// Each node once, in visiting order. A cycle returns from the last node to
+    /// the first.
+    pub order: Vec<&'a str>,
+}
+
+#[derive(Debug, Clone)]
+pub struct Graph<'a, W> {
+    names: Vec<&'a str>,
+    indices: HashMap<&'a str, usize>,
+    /// `weights[from][to]`, `None` where there is no edge.
+    weights: Vec<Vec<Option<W>>>,
+}
+
+impl<'a, W> Default for Graph<'a, W> {
+    fn default() -> Self {
+        Graph {
+            names: Vec::new(),
+            indices: HashMap::new(),
+            weights: Vec::new(),
+        }
+    }
+}
+
+impl<'a, W: Copy> Graph<'a, W> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn len(&self) -> usize {
+        self.names.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.names.is_empty()
+    }
+
+    /// The node names, in the order they were added.
+    pub fn names(&self) -> &[&'a str] {
+        &self.names
+    }
+
+    /// The index of the node called `name`, adding it first if it is new.
+    pub fn add_node(&mut self, name: &'a str) -> usize {
+        if let Some(&index) = self.indices.get(name) {
+            return index;
+        }
+        let index = self.names.len();
+        self.names.push(name);
+        self.indices.insert(name, index);
+        for row in &mut self.weights {
+            row.push(None);
+        }
+        self.weights.push(vec![None; index + 1]);
+        index
+    }
+
+    /// Sets the weight of going from `from` to `to` only.
+    pub fn add_edge(&mut self, from: &'a str, to: &'a str, weight: W) {
+        let (from, to) = (self.add_node(from), self.add_node(to));
+        self.weights[from][to] = Some(weight);
+    }
+
+    /// Sets the weight of going between `a` and `b` in either direction.
+    pub fn add_symmetric_edge(&mut self, a: &'a str, b: &'a str, weight: W) {
+        self.add_edge(a, b, weight);
+        self.add_edge(b, a, weight);
+    }
+
+    pub fn weight(&self, from: &str, to: &str) -> Option<W> {
+        self.weights[*self.indices.get(from)?][*self.indices.get(to)?]
+    }
+}
+
+impl<'a, W: Copy + Default + Ord + Add<Output = W>> Graph<'a, W> {
+    /// A graph where going between two nodes either way is worth both
+    /// directions of this one together, for when each edge counts for both
+    /// of its ends. Pairs missing either direction have no edge.
+    pub fn symmetrised(&self) -> Self {
+        let mut graph = self.clone();
+        for (from, row) in self.weights.iter().enumerate() {
+            for (to, &weight) in row.iter().enumerate() {
+                graph.weights[from][to] = weight.zip(self.weights[to][from]).map(|(a, b)| a + b);
+            }
+        }
+        graph
+    }
+
+    /// Whether the graph is small enough for [`best_path`](Self::best_path)
+    /// and [`best_cycle`](Self::best_cycle) to search.
+    pub fn is_searchable(&self) -> bool {
+        self.len() <= MAX_NODES
+    }
+
+    /// The best route visiting every node once, starting anywhere, or `None`
+    /// if the edges don't allow one or the graph isn't searchable.
+    pub fn best_path(&self, goal: Goal) -> Option<Route<'a, W>> {
+        self.held_karp(goal, 0..self.len(), false)
+    }
+
+    /// The best route visiting every node once and returning to where it
+    /// started, or `None` if the edges don't allow one or the graph isn't
+    /// searchable.
+    pub fn best_cycle(&self, goal: Goal) -> Option<Route<'a, W>> {
+        // Every cycle passes through the first node, so it can start there
+        self.held_karp(goal, 0..1.min(self.len()), true)
+    }
+
+    fn held_karp(
+        &self,
+        goal: Goal,
+        starts: impl Iterator<Item = usize>,
+        cycle: bool,
+    ) -> Option<Route<'a, W>> {
+        if !self.is_searchable() {
+            return None;
+        }
+        let n = self.len();
+        let all = (1usize << n).checked_sub(1).filter(|&all| all > 0)?;
+
+        // best[visited * n + last]: the best value of a route covering the
+        // nodes in `visited` and ending at `last`, and the node before `last`
+        let mut best: Vec<Option<(W, Option<usize>)>> = vec![None; (all + 1) * n];
+        for start in starts {
+            best[(1 << start) * n + start] = Some((W::default(), None));
+        }
+
+        for visited in 1..=all {
+            for last in 0..n {
+                let Some((value, _)) = best[visited * n + last] else {
+                    continue;
+                };
+                for (next, &weight) in self.weights[last].iter().enumerate() {
+                    let Some(weight) = weight.filter(|_| visited & (1 << next) == 0) else {
+                        continue;
+                    };
+                    let candidate = value + weight;
+                    let slot = &mut best[(visited | 1 << next) * n + next];
+                    if slot.is_none_or(|(current, _)| goal.prefers(candidate, current)) {
+                        *slot = Some((candidate, Some(last)));
+                    }
+                }
+            }
+        }
+
+        let (mut last, value) = (0..n)
+            .filter_map(|last| {
+                let (value, _) = best[all * n + last]?;
+                if cycle {
+                    Some((last, value + self.weights[last][0]?))
+                } else {
+                    Some((last, value))
+                }
+            })
+            .reduce(|chosen, other| {
+                if goal.prefers(other.1, chosen.1) {
+                    other
+                } else {
+                    chosen
+                }
+            })?;
+
+        let mut order = Vec::with_capacity(n);
+        let mut visited = all;
+        loop {
+            order.push(self.names[last]);
+            let Some((_, Some(previous))) = best[visited * n + last] else {
+                break;
+            };
+            visited &= !(1 << last);
+            last = previous;
+        }
+        order.reverse();
+        Some(Route { value, order })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn triangle() -> Graph<'static, u32> {
+        let mut graph = Graph::new();
+        graph.add_symmetric_edge("London", "Dublin", 464);
+        graph.add_symmetric_edge("London", "Belfast", 518);
+        graph.add_symmetric_edge("Dublin", "Belfast", 141);
+        graph
+    }
+
+    #[test]
+    fn best_paths() {
+        let graph = triangle();
+        let shortest = graph.best_path(Goal::Min).unwrap();
+        assert_eq!(shortest.value, 605);
+        assert!(
+            shortest.order == ["London", "Dublin", "Belfast"]
+                || shortest.order == ["Belfast", "Dublin", "London"]
+        );
+        assert_eq!(graph.best_path(Goal::Max).unwrap().value, 982);
+    }
+
+    #[test]
+    fn best_cycles() {
+        let mut graph = triangle();
+        graph.add_symmetric_edge("Belfast", "Cork", 10);
+        assert_eq!(graph.best_cycle(Goal::Min), None);
+        graph.add_symmetric_edge("Cork", "London", 20);
+        let cycle = graph.best_cycle(Goal::Min).unwrap();
+        assert_eq!(cycle.value, 464 + 141 + 10 + 20);
+        assert_eq!(cycle.order.len(), 4);
+        assert_eq!(cycle.order[0], "London");
+    }
+
+    #[test]
+    fn asymmetric_weights() {
+        let mut graph = Graph::new();
+        graph.add_edge("a", "b", 1);
+        graph.add_edge("b", "a", 5);
+        graph.add_edge("b", "c", 2);
+        assert_eq!(graph.best_path(Goal::Min).unwrap().order, ["a", "b", "c"]);
+        assert_eq!(graph.symmetrised().weight("a", "b"), Some(6));
+        assert_eq!(graph.symmetrised().weight("b", "c"), None);
+    }
+
+    #[test]
+    fn too_many_nodes() {
+        let names: Vec<String> = (0..MAX_NODES).map(|i| i.to_string()).collect();
+        let mut graph = Graph::new();
+        for pair in names.windows(2) {
+            graph.add_symmetric_edge(&pair[0], &pair[1], 1);
+        }
+        assert!(graph.is_searchable());
+        assert_eq!(
+            graph.best_path(Goal::Min).unwrap().value,
+            MAX_NODES as u32 - 1
+        );
+        graph.add_symmetric_edge(&names[MAX_NODES - 1], "one too many", 1);
+        assert!(!graph.is_searchable());
+        assert_eq!(graph.best_path(Goal::Min), None);
+        assert_eq!(graph.best_cycle(Goal::Min), None);
+    }
+}
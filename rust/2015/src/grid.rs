@@ -0,0 +1,412 @@
+//! Points, directions and grids for the days laid out on a plane.
+//!
+//! Coordinates follow the puzzle text's row-by-row layouts: `x` grows to the
+//! east and `y` to the south, so north is `y - 1`.
+
+use crate::Error;
+use std::{
+    collections::HashMap,
+    fmt::{self, Display},
+    ops::{Index, IndexMut},
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Point {
+    pub x: i32,
+    pub y: i32,
+}
+
+impl Point {
+    pub const ORIGIN: Point = Point::new(0, 0);
+
+    pub const fn new(x: i32, y: i32) -> Self {
+        Point { x, y }
+    }
+
+    pub fn step(self, direction: Direction) -> Point {
+        let (dx, dy) = direction.offset();
+        Point::new(self.x + dx, self.y + dy)
+    }
+
+    pub fn neighbours(self, neighbourhood: Neighbourhood) -> impl Iterator<Item = Point> {
+        neighbourhood
+            .offsets()
+            .iter()
+            .map(move |&(dx, dy)| Point::new(self.x + dx, self.y + dy))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+    North,
+    East,
+    South,
+    West,
+}
+
+impl Direction {
+    pub const ALL: [Direction; 4] = [
+        Direction::North,
+        Direction::East,
+        Direction::South,
+        Direction::West,
+    ];
+
+    pub fn offset(self) -> (i32, i32) {
+        match self {
+            Direction::North => (0, -1),
+            Direction::East => (1, 0),
+            Direction::South => (0, 1),
+            Direction::West => (-1, 0),
+        }
+    }
+
+    /// Reads one of `^`, `>`, `v` or `<`.
+    pub fn from_arrow(arrow: char) -> Option<Direction> {
+        match arrow {
+            '^' => Some(Direction::North),
+            '>' => Some(Direction::East),
+            'v' => Some(Direction::South),
+            '<' => Some(Direction::West),
+            _ => None,
+        }
+    }
+}
+
+/// Which surrounding points count as neighbours.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Neighbourhood {
+    /// The points sharing an edge.
+    Four,
+    /// The points sharing an edge or a corner.
+    Eight,
+}
+
+impl Neighbourhood {
+    fn offsets(self) -> &'static [(i32, i32)] {
+        match self {
+            Neighbourhood::Four => &[(0, -1), (1, 0), (0, 1), (-1, 0)],
+            Neighbourhood::Eight => &[
+                (-1, -1),
+                (0, -1),
+                (1, -1),
+                (1, 0),
+                (1, 1),
+                (0, 1),
+                (-1, 1),
+                (-1, 0),
+            ],
+        }
+    }
+}
+
+/// A fixed-size grid with its top-left corner at the origin, stored row by row.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Grid {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+
+    /// Parses one row per line, turning each character into a cell with
+    /// `cell`, which names what it expected when it returns `Err`.
+    pub fn parse(
+        text: &str,
+        mut cell: impl FnMut(char) -> Result<T, &'static str>,
+    ) -> Result<Self, Error> {
+        let mut width = None;
+        let mut height = 0;
+        let mut cells = Vec::new();
+        for line in text.lines() {
+            let before = cells.len();
+            for (i, c) in line.char_indices() {
+                cells.push(
+                    cell(c).map_err(|expected| Error::at(&line[i..i + c.len_utf8()], expected))?,
+                );
+            }
+            let line_width = cells.len() - before;
+            if *width.get_or_insert(line_width) != line_width {
+                return Err(Error::at(
+                    line,
+                    format!(
+                        "Expected every line to be {} characters long",
+                        width.unwrap_or_default()
+                    ),
+                ));
+            }
+            height += 1;
+        }
+        Ok(Grid {
+            width: width.unwrap_or_default(),
+            height,
+            cells,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, point: Point) -> bool {
+        (0..self.width as i32).contains(&point.x) && (0..self.height as i32).contains(&point.y)
+    }
+
+    fn offset(&self, point: Point) -> Option<usize> {
+        self.contains(point)
+            .then(|| point.y as usize * self.width + point.x as usize)
+    }
+
+    pub fn get(&self, point: Point) -> Option<&T> {
+        self.offset(point).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut(&mut self, point: Point) -> Option<&mut T> {
+        self.offset(point).map(|i| &mut self.cells[i])
+    }
+
+    /// Every point of the grid, row by row.
+    pub fn points(&self) -> impl Iterator<Item = Point> {
+        let (width, height) = (self.width as i32, self.height as i32);
+        (0..height).flat_map(move |y| (0..width).map(move |x| Point::new(x, y)))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.points().zip(&self.cells)
+    }
+
+    pub fn values(&self) -> impl Iterator<Item = &T> {
+        self.cells.iter()
+    }
+
+    /// The neighbours of `point` that lie inside the grid.
+    pub fn neighbours(
+        &self,
+        point: Point,
+        neighbourhood: Neighbourhood,
+    ) -> impl Iterator<Item = Point> + '_ {
+        point
+            .neighbours(neighbourhood)
+            .filter(|&neighbour| self.contains(neighbour))
+    }
+
+    /// A grid of the same size with `f` applied to every cell.
+    pub fn map<U>(&self, mut f: impl FnMut(Point, &T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.iter().map(|(point, value)| f(point, value)).collect(),
+        }
+    }
+
+    /// One line of text per row, drawing each cell with `cell`.
+    pub fn render(&self, cell: impl Fn(&T) -> char) -> String {
+        self.cells
+            .chunks(self.width.max(1))
+            .map(|row| row.iter().map(&cell).collect::<String>())
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+impl Grid<bool> {
+    /// Parses a picture of lights, `#` for on and `.` for off.
+    pub fn parse_lights(text: &str) -> Result<Self, Error> {
+        Grid::parse(text, |c| match c {
+            '#' => Ok(true),
+            '.' => Ok(false),
+            _ => Err("Expected # or ."),
+        })
+    }
+
+    pub fn count_on(&self) -> usize {
+        self.values().filter(|&&on| on).count()
+    }
+}
+
+impl Display for Grid<bool> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.render(|&on| if on { '#' } else { '.' }))
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point) -> &T {
+        self.get(point).unwrap_or_else(|| {
+            panic!(
+                "{point:?} is outside the {}x{} grid",
+                self.width, self.height
+            )
+        })
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, point: Point) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        self.get_mut(point)
+            .unwrap_or_else(|| panic!("{point:?} is outside the {width}x{height} grid"))
+    }
+}
+
+/// A grid without bounds that only stores the cells that have been set.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SparseGrid<T> {
+    cells: HashMap<Point, T>,
+}
+
+impl<T> Default for SparseGrid<T> {
+    fn default() -> Self {
+        SparseGrid {
+            cells: HashMap::new(),
+        }
+    }
+}
+
+impl<T> SparseGrid<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// How many cells have been set.
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn get(&self, point: Point) -> Option<&T> {
+        self.cells.get(&point)
+    }
+
+    pub fn insert(&mut self, point: Point, value: T) -> Option<T> {
+        self.cells.insert(point, value)
+    }
+
+    /// The cell at `point`, set to the default value first if it wasn't yet.
+    pub fn get_or_default(&mut self, point: Point) -> &mut T
+    where
+        T: Default,
+    {
+        self.cells.entry(point).or_default()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.cells.iter().map(|(&point, value)| (point, value))
+    }
+
+    /// The top-left and bottom-right corners of the smallest rectangle holding
+    /// every cell that has been set.
+    pub fn bounds(&self) -> Option<(Point, Point)> {
+        let mut points = self.cells.keys();
+        let first = *points.next()?;
+        Some(points.fold((first, first), |(min, max), point| {
+            (
+                Point::new(min.x.min(point.x), min.y.min(point.y)),
+                Point::new(max.x.max(point.x), max.y.max(point.y)),
+            )
+        }))
+    }
+
+    /// The cells inside [`bounds`](Self::bounds) as text, drawing each set cell
+    /// with `cell` and the others as `empty`.
+    pub fn render(&self, empty: char, cell: impl Fn(&T) -> char) -> String {
+        let Some((min, max)) = self.bounds() else {
+            return String::new();
+        };
+        (min.y..=max.y)
+            .map(|y| {
+                (min.x..=max.x)
+                    .map(|x| self.get(Point::new(x, y)).map_or(empty, &cell))
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+impl<T> FromIterator<(Point, T)> for SparseGrid<T> {
+    fn from_iter<I: IntoIterator<Item = (Point, T)>>(iter: I) -> Self {
+        SparseGrid {
+            cells: iter.into_iter().collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const GLIDER: &str = ".#.\n..#\n###";
+
+    #[test]
+    fn parses_and_renders_lights() {
+        let grid = Grid::parse_lights(GLIDER).unwrap();
+        assert_eq!((grid.width(), grid.height()), (3, 3));
+        assert!(grid[Point::new(1, 0)] && !grid[Point::new(0, 1)]);
+        assert_eq!(grid.count_on(), 5);
+        assert_eq!(grid.to_string(), GLIDER);
+    }
+
+    #[test]
+    fn rejects_malformed_lights() {
+        let error = Grid::parse_lights(".#.\n.x.").unwrap_err();
+        assert_eq!(
+            (error.message(), error.text()),
+            ("Expected # or .", Some("x"))
+        );
+        let error = Grid::parse_lights(".#.\n..").unwrap_err();
+        assert_eq!(error.text(), Some(".."));
+    }
+
+    #[test]
+    fn neighbours_stay_inside_the_grid() {
+        let grid = Grid::new(3, 3, ());
+        assert_eq!(
+            grid.neighbours(Point::ORIGIN, Neighbourhood::Four).count(),
+            2
+        );
+        assert_eq!(
+            grid.neighbours(Point::ORIGIN, Neighbourhood::Eight).count(),
+            3
+        );
+        assert_eq!(
+            grid.neighbours(Point::new(1, 1), Neighbourhood::Eight)
+                .count(),
+            8
+        );
+        assert_eq!(Point::ORIGIN.step(Direction::North), Point::new(0, -1));
+    }
+
+    #[test]
+    fn sparse_grids_grow_in_every_direction() {
+        let mut grid = SparseGrid::<u32>::new();
+        let mut point = Point::ORIGIN;
+        for arrow in "^<<vvv".chars() {
+            point = point.step(Direction::from_arrow(arrow).unwrap());
+            *grid.get_or_default(point) += 1;
+        }
+        assert_eq!(grid.len(), 6);
+        assert_eq!(grid.bounds(), Some((Point::new(-2, -1), Point::new(0, 2))));
+        assert_eq!(grid.render('.', |_| '#'), "###\n#..\n#..\n#..");
+    }
+}
@@ -0,0 +1,293 @@
+//! Puzzle input loading.
+//!
+//! Inputs live in `inputs/<year>/day_<NN>/<name>.txt` at the repository root,
+//! the same layout the Deno `readInput(year, day, filename)` helper reads. By
+//! default they are read from disk when a solution runs; building with the
+//! `embed-inputs` feature bakes them into the binary instead.
+//!
+//! Most days read a single `input.txt`, but a day may read several named files
+//! (day 21's `boss.txt` and `shop.txt`), which arrive together as a [`PuzzleInput`].
+//!
+//! Next to the input, `answers.txt` records the accepted answers, one line per part.
+
+use crate::{Error, Part};
+use std::{
+    env, fs,
+    io::{self, Read},
+    ops::Deref,
+    path::PathBuf,
+};
+
+/// The files a day reads, keyed by name. The first one is the day's primary
+/// input, which the [`Deref`] to `str` exposes directly.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PuzzleInput {
+    files: Vec<(String, String)>,
+    day: Option<(u16, u8)>,
+}
+
+impl PuzzleInput {
+    /// A single-file input, as most days and all examples use.
+    pub fn new(text: impl Into<String>) -> Self {
+        PuzzleInput {
+            files: vec![("input".to_string(), text.into())],
+            day: None,
+        }
+    }
+
+    /// A single-file input whose primary file isn't called `input`, like
+    /// day 21's `boss`.
+    pub fn named(name: impl Into<String>, text: impl Into<String>) -> Self {
+        PuzzleInput {
+            files: vec![(name.into(), text.into())],
+            day: None,
+        }
+    }
+
+    pub fn with_file(mut self, name: impl Into<String>, text: impl Into<String>) -> Self {
+        self.files.push((name.into(), text.into()));
+        self
+    }
+
+    /// Reads every file in `names`; the primary one (the first) comes from
+    /// `primary`, the rest from their default location.
+    pub fn load(year: u16, day: u8, names: &[&str], primary: &InputSource) -> Result<Self, Error> {
+        let files = names
+            .iter()
+            .enumerate()
+            .map(|(i, name)| {
+                let source = if i == 0 {
+                    primary
+                } else {
+                    &InputSource::Default
+                };
+                Ok((name.to_string(), source.read(year, day, name)?))
+            })
+            .collect::<Result<Vec<_>, Error>>()?;
+        if files.is_empty() {
+            return Err(Error::new("Reads no input files").in_day(year, day));
+        }
+        Ok(PuzzleInput {
+            files,
+            day: Some((year, day)),
+        })
+    }
+
+    pub fn text(&self) -> &str {
+        &self.files[0].1
+    }
+
+    pub fn file(&self, name: &str) -> Result<&str, Error> {
+        self.files
+            .iter()
+            .find(|(file, _)| file == name)
+            .map(|(_, text)| text.as_str())
+            .ok_or_else(|| Error::new(format!("Missing input file: {name}.txt")))
+    }
+
+    /// Every file's name and text, primary first.
+    pub fn files(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.files
+            .iter()
+            .map(|(name, text)| (name.as_str(), text.as_str()))
+    }
+
+    /// Adds this input's day, and where in its files the error was found.
+    pub fn locate(&self, error: Error) -> Error {
+        let error = error.locate(&self.files);
+        match self.day {
+            Some((year, day)) => error.in_day(year, day),
+            None => error,
+        }
+    }
+}
+
+impl Deref for PuzzleInput {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        self.text()
+    }
+}
+
+impl From<&str> for PuzzleInput {
+    fn from(text: &str) -> Self {
+        PuzzleInput::new(text)
+    }
+}
+
+/// Where the runner should take a day's input from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    /// The day's own input from the `inputs` directory (or the binary, with `embed-inputs`).
+    Default,
+    Path(PathBuf),
+    Stdin,
+}
+
+impl InputSource {
+    /// Interprets a command-line argument, where `-` means standard input.
+    pub fn from_arg(arg: &str) -> Self {
+        if arg == "-" {
+            InputSource::Stdin
+        } else {
+            InputSource::Path(PathBuf::from(arg))
+        }
+    }
+
+    pub fn read(&self, year: u16, day: u8, name: &str) -> Result<String, Error> {
+        match self {
+            InputSource::Default => default_input(year, day, name),
+            InputSource::Path(path) => fs::read_to_string(path)
+                .map_err(|e| Error::new(format!("Could not read {}: {e}", path.display()))),
+            InputSource::Stdin => {
+                let mut input = String::new();
+                io::stdin()
+                    .read_to_string(&mut input)
+                    .map(|_| input)
+                    .map_err(|e| Error::new(format!("Could not read stdin: {e}")))
+            }
+        }
+        .map_err(|e| e.in_day(year, day))
+    }
+}
+
+/// The `inputs` directory, overridable with the `AOC_INPUTS` environment variable.
+pub fn inputs_dir() -> PathBuf {
+    env::var_os("AOC_INPUTS")
+        .map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from(concat!(env!("CARGO_MANIFEST_DIR"), "/../../inputs")))
+}
+
+pub fn input_path(year: u16, day: u8, name: &str) -> PathBuf {
+    inputs_dir()
+        .join(year.to_string())
+        .join(format!("day_{day:02}"))
+        .join(format!("{name}.txt"))
+}
+
+pub fn read_input(year: u16, day: u8, name: &str) -> Result<String, Error> {
+    let path = input_path(year, day, name);
+    fs::read_to_string(&path)
+        .map_err(|e| Error::new(format!("Could not read {}: {e}", path.display())))
+}
+
+/// The accepted answers recorded for `day`, for the parts that have one.
+/// A day without an `answers.txt` has none.
+pub fn recorded_answers(year: u16, day: u8) -> Result<Vec<(Part, String)>, Error> {
+    let Some(answers) = default_answers(year, day)? else {
+        return Ok(Vec::new());
+    };
+    Ok(Part::BOTH
+        .into_iter()
+        .zip(answers.lines().map(str::trim))
+        .filter(|(_, answer)| !answer.is_empty())
+        .map(|(part, answer)| (part, answer.to_string()))
+        .collect())
+}
+
+#[cfg(not(feature = "embed-inputs"))]
+fn default_answers(year: u16, day: u8) -> Result<Option<String>, Error> {
+    let path = input_path(year, day, "answers");
+    match fs::read_to_string(&path) {
+        Ok(answers) => Ok(Some(answers)),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(None),
+        Err(e) => {
+            Err(Error::new(format!("Could not read {}: {e}", path.display())).in_day(year, day))
+        }
+    }
+}
+
+#[cfg(feature = "embed-inputs")]
+fn default_answers(year: u16, day: u8) -> Result<Option<String>, Error> {
+    Ok(embedded_input(year, day, "answers").map(str::to_string))
+}
+
+#[cfg(not(feature = "embed-inputs"))]
+fn default_input(year: u16, day: u8, name: &str) -> Result<String, Error> {
+    read_input(year, day, name)
+}
+
+#[cfg(feature = "embed-inputs")]
+fn default_input(year: u16, day: u8, name: &str) -> Result<String, Error> {
+    embedded_input(year, day, name)
+        .map(str::to_string)
+        .ok_or_else(|| Error::new(format!("No {name}.txt embedded")))
+}
+
+#[cfg(feature = "embed-inputs")]
+#[macro_export]
+macro_rules! day_input {
+    ($day:literal) => {
+        $crate::day_input!($day, "input")
+    };
+    ($day:literal, $name:literal) => {
+        include_str!(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/../../inputs/2015/day_",
+            $day,
+            "/",
+            $name,
+            ".txt"
+        ))
+    };
+}
+
+/// The input file `name` bundled into the binary for `day`, if there is one.
+#[cfg(feature = "embed-inputs")]
+pub fn embedded_input(year: u16, day: u8, name: &str) -> Option<&'static str> {
+    match (year, day, name) {
+        (2015, 1, "input") => Some(day_input!("01")),
+        (2015, 2, "input") => Some(day_input!("02")),
+        (2015, 3, "input") => Some(day_input!("03")),
+        (2015, 4, "input") => Some(day_input!("04")),
+        (2015, 5, "input") => Some(day_input!("05")),
+        (2015, 6, "input") => Some(day_input!("06")),
+        (2015, 7, "input") => Some(day_input!("07")),
+        (2015, 8, "input") => Some(day_input!("08")),
+        (2015, 9, "input") => Some(day_input!("09")),
+        (2015, 10, "input") => Some(day_input!("10")),
+        (2015, 11, "input") => Some(day_input!("11")),
+        (2015, 12, "input") => Some(day_input!("12")),
+        (2015, 13, "input") => Some(day_input!("13")),
+        (2015, 14, "input") => Some(day_input!("14")),
+        (2015, 15, "input") => Some(day_input!("15")),
+        (2015, 16, "input") => Some(day_input!("16")),
+        (2015, 17, "input") => Some(day_input!("17")),
+        (2015, 18, "input") => Some(day_input!("18")),
+        (2015, 19, "input") => Some(day_input!("19")),
+        (2015, 20, "input") => Some(day_input!("20")),
+        (2015, 21, "boss") => Some(day_input!("21", "boss")),
+        (2015, 21, "shop") => Some(day_input!("21", "shop")),
+        (2015, 22, "input") => Some(day_input!("22")),
+        (2015, 23, "input") => Some(day_input!("23")),
+        (2015, 24, "input") => Some(day_input!("24")),
+        (2015, 25, "input") => Some(day_input!("25")),
+        (2015, 1, "answers") => Some(day_input!("01", "answers")),
+        (2015, 2, "answers") => Some(day_input!("02", "answers")),
+        (2015, 3, "answers") => Some(day_input!("03", "answers")),
+        (2015, 4, "answers") => Some(day_input!("04", "answers")),
+        (2015, 5, "answers") => Some(day_input!("05", "answers")),
+        (2015, 6, "answers") => Some(day_input!("06", "answers")),
+        (2015, 7, "answers") => Some(day_input!("07", "answers")),
+        (2015, 8, "answers") => Some(day_input!("08", "answers")),
+        (2015, 9, "answers") => Some(day_input!("09", "answers")),
+        (2015, 10, "answers") => Some(day_input!("10", "answers")),
+        (2015, 11, "answers") => Some(day_input!("11", "answers")),
+        (2015, 12, "answers") => Some(day_input!("12", "answers")),
+        (2015, 13, "answers") => Some(day_input!("13", "answers")),
+        (2015, 14, "answers") => Some(day_input!("14", "answers")),
+        (2015, 15, "answers") => Some(day_input!("15", "answers")),
+        (2015, 16, "answers") => Some(day_input!("16", "answers")),
+        (2015, 17, "answers") => Some(day_input!("17", "answers")),
+        (2015, 18, "answers") => Some(day_input!("18", "answers")),
+        (2015, 19, "answers") => Some(day_input!("19", "answers")),
+        (2015, 20, "answers") => Some(day_input!("20", "answers")),
+        (2015, 21, "answers") => Some(day_input!("21", "answers")),
+        (2015, 22, "answers") => Some(day_input!("22", "answers")),
+        (2015, 23, "answers") => Some(day_input!("23", "answers")),
+        (2015, 24, "answers") => Some(day_input!("24", "answers")),
+        (2015, 25, "answers") => Some(day_input!("25", "answers")),
+        _ => None,
+    }
+}
@@ -1,13 +1,379 @@
-#[macro_export]
-macro_rules! day_input {
-    ($day:literal) => {
-        include_str!(concat!("../../../inputs/2015/day_", $day, "/input.txt"))
-    };
-}
-
-pub fn day_21_input() -> (&'static str, &'static str) {
-    (
-        include_str!("../../../inputs/2015/day_21/shop.txt"),
-        include_str!("../../../inputs/2015/day_21/boss.txt"),
-    )
+pub use error::Error;
+use input::PuzzleInput;
+use random::Rng;
+use serde_json::Value;
+use std::{
+    fmt::{self, Display},
+    hint::black_box,
+    time::{Duration, Instant},
+};
+
+#[path = "../day_01/mod.rs"]
+pub mod day_01;
+#[path = "../day_02/mod.rs"]
+pub mod day_02;
+#[path = "../day_03/mod.rs"]
+pub mod day_03;
+#[path = "../day_04/mod.rs"]
+pub mod day_04;
+#[path = "../day_05/mod.rs"]
+pub mod day_05;
+#[path = "../day_06/mod.rs"]
+pub mod day_06;
+#[path = "../day_07/mod.rs"]
+pub mod day_07;
+#[path = "../day_08/mod.rs"]
+pub mod day_08;
+#[path = "../day_09/mod.rs"]
+pub mod day_09;
+#[path = "../day_10/mod.rs"]
+pub mod day_10;
+#[path = "../day_11/mod.rs"]
+pub mod day_11;
+#[path = "../day_12/mod.rs"]
+pub mod day_12;
+#[path = "../day_13/mod.rs"]
+pub mod day_13;
+#[path = "../day_14/mod.rs"]
+pub mod day_14;
+#[path = "../day_15/mod.rs"]
+pub mod day_15;
+#[path = "../day_16/mod.rs"]
+pub mod day_16;
+#[path = "../day_17/mod.rs"]
+pub mod day_17;
+#[path = "../day_18/mod.rs"]
+pub mod day_18;
+#[path = "../day_19/mod.rs"]
+pub mod day_19;
+#[path = "../day_20/mod.rs"]
+pub mod day_20;
+#[path = "../day_21/mod.rs"]
+pub mod day_21;
+#[path = "../day_22/mod.rs"]
+pub mod day_22;
+#[path = "../day_23/mod.rs"]
+pub mod day_23;
+#[path = "../day_24/mod.rs"]
+pub mod day_24;
+#[path = "../day_25/mod.rs"]
+pub mod day_25;
+
+pub mod check;
+pub mod combinatorics;
+pub mod error;
+pub mod graph;
+pub mod grid;
+pub mod input;
+pub mod parse;
+pub mod progress;
+pub mod random;
+#[cfg(test)]
+mod testing;
+
+pub const YEAR: u16 = 2015;
+
+/// A day's puzzle: the input is parsed once and both parts are answered from it.
+pub trait Solution {
+    type Input<'a>;
+    type Output1: Display;
+    type Output2: Display;
+
+    /// Names of the files in `inputs/<year>/day_<NN>/` this day reads, primary first.
+    const INPUT_FILES: &'static [&'static str] = &["input"];
+
+    /// The [`generate`](Self::generate) size that gives an input about as
+    /// large as the puzzle's.
+    const PUZZLE_SIZE: usize;
+
+    /// Names of the puzzle's parameters that aren't part of the input, like
+    /// day 14's race length. They live in the day's `Config`, whose defaults are
+    /// the puzzle's.
+    const PARAMETERS: &'static [&'static str] = &[];
+
+    fn parse<'a>(&self, input: &'a PuzzleInput) -> Result<Self::Input<'a>, Error>;
+    fn part_1(&self, input: &Self::Input<'_>) -> Self::Output1;
+    fn part_2(&self, input: &Self::Input<'_>) -> Self::Output2;
+
+    /// Structured extras about an answer beyond its value, such as how it was
+    /// reached. The runner includes them in its JSON output.
+    fn details_1(&self, _answer: &Self::Output1) -> Option<Value> {
+        None
+    }
+    fn details_2(&self, _answer: &Self::Output2) -> Option<Value> {
+        None
+    }
+
+    /// A random well-formed input for benchmarking and fuzzing. What `size`
+    /// counts is up to the day, e.g. the wires of day 7's circuit or the
+    /// cities of day 9.
+    fn generate(&self, rng: &mut Rng, size: usize) -> PuzzleInput;
+
+    /// Sets the parameter `name`, always one of [`PARAMETERS`](Self::PARAMETERS),
+    /// from its command-line text.
+    fn configure(&mut self, name: &str, _value: &str) -> Result<(), Error> {
+        unreachable!("No parameter {name}")
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const BOTH: [Part; 2] = [Part::One, Part::Two];
+
+    pub fn number(self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Part::One => write!(f, "Part 1"),
+            Part::Two => write!(f, "Part 2"),
+        }
+    }
+}
+
+/// A part's answer when a valid input can have none, like a map without a
+/// route through every city. It displays as the answer, or as `none` and why.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Found<T> {
+    Answer(T),
+    Nothing(&'static str),
+}
+
+impl<T> Found<T> {
+    /// `Nothing(why)` if `option` is `None`.
+    pub fn from_option(option: Option<T>, why: &'static str) -> Self {
+        option.map_or(Found::Nothing(why), Found::Answer)
+    }
+
+    /// The answer; panics with the reason there isn't one otherwise.
+    pub fn unwrap(self) -> T {
+        match self {
+            Found::Answer(answer) => answer,
+            Found::Nothing(why) => panic!("No answer: {why}"),
+        }
+    }
+}
+
+impl<T: Display> Display for Found<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Found::Answer(answer) => answer.fmt(f),
+            Found::Nothing(why) => write!(f, "none ({why})"),
+        }
+    }
+}
+
+/// How long each stage of one run of a day took.
+#[derive(Debug, Clone)]
+pub struct Timings {
+    pub parse: Duration,
+    pub parts: Vec<(Part, Duration)>,
+}
+
+/// One part's answer with everything known about how it was produced.
+#[derive(Debug, Clone)]
+pub struct Answer {
+    pub part: Part,
+    pub answer: String,
+    pub details: Option<Value>,
+    /// Time taken by the part itself, not counting parsing or formatting.
+    pub duration: Duration,
+}
+
+/// Object-safe view of a [`Solution`], so days with different input and
+/// output types can sit side by side in the registry.
+pub trait DynSolution {
+    fn input_files(&self) -> &'static [&'static str];
+    fn solve(&self, input: &PuzzleInput, parts: &[Part]) -> Result<Vec<(Part, String)>, Error>;
+    /// Runs like [`solve`](Self::solve), but also reports each part's details
+    /// and how long it took.
+    fn answers(&self, input: &PuzzleInput, parts: &[Part]) -> Result<Vec<Answer>, Error>;
+    /// Runs like [`solve`](Self::solve), but times parsing and each part instead
+    /// of formatting the answers.
+    fn time(&self, input: &PuzzleInput, parts: &[Part]) -> Result<Timings, Error>;
+    fn puzzle_size(&self) -> usize;
+    /// The day's generated input for `seed` and `size`, which is always the
+    /// same for the same pair.
+    fn generate_input(&self, seed: u64, size: usize) -> PuzzleInput;
+    /// A copy of the day with each `(name, value)` parameter set, in order.
+    fn configured(&self, parameters: &[(String, String)]) -> Result<Box<dyn DynSolution>, Error>;
+}
+
+impl<S: Solution + Clone + 'static> DynSolution for S {
+    fn input_files(&self) -> &'static [&'static str] {
+        S::INPUT_FILES
+    }
+
+    fn solve(&self, input: &PuzzleInput, parts: &[Part]) -> Result<Vec<(Part, String)>, Error> {
+        Ok(self
+            .answers(input, parts)?
+            .into_iter()
+            .map(|answer| (answer.part, answer.answer))
+            .collect())
+    }
+
+    fn answers(&self, input: &PuzzleInput, parts: &[Part]) -> Result<Vec<Answer>, Error> {
+        let input = self.parse(input).map_err(|e| input.locate(e))?;
+        Ok(parts
+            .iter()
+            .map(|&part| {
+                let start = Instant::now();
+                let (answer, details, duration) = match part {
+                    Part::One => {
+                        let answer = self.part_1(&input);
+                        let duration = start.elapsed();
+                        (answer.to_string(), self.details_1(&answer), duration)
+                    }
+                    Part::Two => {
+                        let answer = self.part_2(&input);
+                        let duration = start.elapsed();
+                        (answer.to_string(), self.details_2(&answer), duration)
+                    }
+                };
+                Answer {
+                    part,
+                    answer,
+                    details,
+                    duration,
+                }
+            })
+            .collect())
+    }
+
+    fn time(&self, input: &PuzzleInput, parts: &[Part]) -> Result<Timings, Error> {
+        let start = Instant::now();
+        let input = self.parse(input).map_err(|e| input.locate(e))?;
+        let parse = start.elapsed();
+
+        let parts = parts
+            .iter()
+            .map(|&part| {
+                let start = Instant::now();
+                match part {
+                    Part::One => {
+                        black_box(self.part_1(&input));
+                    }
+                    Part::Two => {
+                        black_box(self.part_2(&input));
+                    }
+                }
+                (part, start.elapsed())
+            })
+            .collect();
+        Ok(Timings { parse, parts })
+    }
+
+    fn puzzle_size(&self) -> usize {
+        S::PUZZLE_SIZE
+    }
+
+    fn generate_input(&self, seed: u64, size: usize) -> PuzzleInput {
+        self.generate(&mut Rng::new(seed), size)
+    }
+
+    fn configured(&self, parameters: &[(String, String)]) -> Result<Box<dyn DynSolution>, Error> {
+        let mut solution = self.clone();
+        for (name, value) in parameters {
+            if !S::PARAMETERS.contains(&name.as_str()) {
+                return Err(Error::new(match S::PARAMETERS {
+                    [] => format!("Unknown parameter {name}: this day has none"),
+                    known => format!("Unknown parameter {name}, expected {}", known.join(", ")),
+                }));
+            }
+            solution.configure(name, value)?;
+        }
+        Ok(Box::new(solution))
+    }
+}
+
+pub fn solution(year: u16, day: u8) -> Option<&'static dyn DynSolution> {
+    match (year, day) {
+        (2015, 1) => Some(&day_01::Day01),
+        (2015, 2) => Some(&day_02::Day02),
+        (2015, 3) => Some(&day_03::Day03::PUZZLE),
+        (2015, 4) => Some(&day_04::Day04),
+        (2015, 5) => Some(&day_05::Day05::PUZZLE),
+        (2015, 6) => Some(&day_06::Day06),
+        (2015, 7) => Some(&day_07::Day07),
+        (2015, 8) => Some(&day_08::Day08),
+        (2015, 9) => Some(&day_09::Day09),
+        (2015, 10) => Some(&day_10::Day10::PUZZLE),
+        (2015, 11) => Some(&day_11::Day11),
+        (2015, 12) => Some(&day_12::Day12),
+        (2015, 13) => Some(&day_13::Day13),
+        (2015, 14) => Some(&day_14::Day14::PUZZLE),
+        (2015, 15) => Some(&day_15::Day15::PUZZLE),
+        (2015, 16) => Some(&day_16::Day16::PUZZLE),
+        (2015, 17) => Some(&day_17::Day17::PUZZLE),
+        (2015, 18) => Some(&day_18::Day18::PUZZLE),
+        (2015, 19) => Some(&day_19::Day19),
+        (2015, 20) => Some(&day_20::Day20),
+        (2015, 21) => Some(&day_21::Day21::PUZZLE),
+        (2015, 22) => Some(&day_22::Day22::PUZZLE),
+        (2015, 23) => Some(&day_23::Day23),
+        (2015, 24) => Some(&day_24::Day24),
+        (2015, 25) => Some(&day_25::Day25),
+        _ => None,
+    }
+}
+
+pub fn days(year: u16) -> impl Iterator<Item = u8> {
+    (1..=25).filter(move |&day| solution(year, day).is_some())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn every_day_parses_its_generated_input() {
+        for day in days(YEAR) {
+            let solution = solution(YEAR, day).unwrap();
+            let input = solution.generate_input(u64::from(day), solution.puzzle_size());
+            if let Err(e) = solution.solve(&input, &[]) {
+                panic!("Day {day} rejected its generated input: {e}");
+            }
+        }
+    }
+
+    #[test]
+    fn parameters_override_the_puzzle() {
+        let set = |day, parameters: &[(&str, &str)]| {
+            let parameters: Vec<_> = parameters
+                .iter()
+                .map(|&(name, value)| (name.to_string(), value.to_string()))
+                .collect();
+            solution(YEAR, day).unwrap().configured(&parameters)
+        };
+        let race = PuzzleInput::new(
+            "Comet can fly 14 km/s for 10 seconds, but then must rest for 127 seconds.",
+        );
+        let day_14 = set(14, &[("seconds", "1000")]).unwrap();
+        assert_eq!(day_14.solve(&race, &[Part::One]).unwrap()[0].1, "1120");
+
+        let error = |day, parameters| set(day, parameters).err().unwrap().to_string();
+        assert_eq!(
+            error(14, &[("minutes", "5")]),
+            "Unknown parameter minutes, expected seconds"
+        );
+        assert_eq!(
+            error(1, &[("floors", "5")]),
+            "Unknown parameter floors: this day has none"
+        );
+        assert_eq!(
+            error(22, &[("mana", "lots")]),
+            "Invalid amount of mana: \"lots\""
+        );
+    }
 }
@@ -0,0 +1,256 @@
+//! Helpers for the line formats the puzzles use, so a day can describe what its
+//! input looks like instead of indexing into split pieces. Every failure is an
+//! [`Error`] pointing at the offending text.
+
+use crate::Error;
+use std::str::FromStr;
+
+/// Parses `text` as a number, reporting it as an invalid `what` otherwise.
+pub fn parse_number<T: FromStr>(text: &str, what: &str) -> Result<T, Error> {
+    text.parse()
+        .map_err(|_| Error::at(text, format!("Invalid {what}")))
+}
+
+/// A line template with named `{placeholders}`, e.g.
+/// `"{from} to {to} = {distance}"`. Each placeholder captures the shortest text
+/// up to the literal that follows it; the last one runs to the end of the line.
+pub struct Pattern(&'static str);
+
+impl Pattern {
+    /// Adjacent placeholders can't be told apart, so a template must put some
+    /// literal text between each pair.
+    pub const fn new(template: &'static str) -> Self {
+        Pattern(template)
+    }
+
+    /// The text matched by each placeholder, in order.
+    pub fn captures<'a>(&self, line: &'a str) -> Result<Vec<&'a str>, Error> {
+        let mismatch = || Error::at(line, format!("Expected \"{}\"", self.0));
+
+        let mut pieces = self.0.split('{');
+        let prefix = pieces.next().unwrap_or_default();
+        let mut rest = line.strip_prefix(prefix).ok_or_else(mismatch)?;
+        let mut pieces = pieces.peekable();
+        let mut captures = Vec::new();
+
+        while let Some(piece) = pieces.next() {
+            let (_, literal) = piece
+                .split_once('}')
+                .unwrap_or_else(|| panic!("Unclosed placeholder in pattern {:?}", self.0));
+            let end = if pieces.peek().is_none() {
+                rest.strip_suffix(literal).ok_or_else(mismatch)?.len()
+            } else {
+                assert!(
+                    !literal.is_empty(),
+                    "Adjacent placeholders in pattern {:?}",
+                    self.0
+                );
+                rest.find(literal).ok_or_else(mismatch)?
+            };
+            captures.push(&rest[..end]);
+            rest = &rest[end + literal.len()..];
+        }
+
+        if !rest.is_empty() {
+            return Err(mismatch());
+        }
+        Ok(captures)
+    }
+
+    /// Matches `line` and converts each capture to its type in `T`, a tuple
+    /// with one element per placeholder.
+    pub fn parse<'a, T: FromCaptures<'a>>(&self, line: &'a str) -> Result<T, Error> {
+        let names: Vec<&str> = self
+            .0
+            .split('{')
+            .skip(1)
+            .map(|piece| piece.split_once('}').map_or(piece, |(name, _)| name))
+            .collect();
+        T::from_captures(&self.captures(line)?, &names)
+    }
+}
+
+/// A type a single placeholder can be converted to: the matched text itself,
+/// or a number.
+pub trait Capture<'a>: Sized {
+    fn from_capture(text: &'a str, name: &str) -> Result<Self, Error>;
+}
+
+impl<'a> Capture<'a> for &'a str {
+    fn from_capture(text: &'a str, _: &str) -> Result<Self, Error> {
+        Ok(text)
+    }
+}
+
+macro_rules! number_captures {
+    ($($number:ty),*) => {
+        $(
+            impl<'a> Capture<'a> for $number {
+                fn from_capture(text: &'a str, name: &str) -> Result<Self, Error> {
+                    parse_number(text, name)
+                }
+            }
+        )*
+    };
+}
+
+number_captures!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+/// A tuple of [`Capture`]s, one per placeholder of a [`Pattern`].
+pub trait FromCaptures<'a>: Sized {
+    fn from_captures(captures: &[&'a str], names: &[&str]) -> Result<Self, Error>;
+}
+
+macro_rules! tuple_captures {
+    ($($capture:ident),+) => {
+        impl<'a, $($capture: Capture<'a>),+> FromCaptures<'a> for ($($capture,)+) {
+            fn from_captures(captures: &[&'a str], names: &[&str]) -> Result<Self, Error> {
+                let mut captures = captures.iter().zip(names);
+                let tuple = ($({
+                    let (text, name) = captures
+                        .next()
+                        .expect("Pattern has fewer placeholders than captured types");
+                    $capture::from_capture(text, name)?
+                },)+);
+                assert!(
+                    captures.next().is_none(),
+                    "Pattern has more placeholders than captured types"
+                );
+                Ok(tuple)
+            }
+        }
+    };
+}
+
+tuple_captures!(A);
+tuple_captures!(A, B);
+tuple_captures!(A, B, C);
+tuple_captures!(A, B, C, D);
+tuple_captures!(A, B, C, D, E);
+tuple_captures!(A, B, C, D, E, F);
+
+/// Every integer in `text`, in order. A `-` directly before the digits makes
+/// it negative.
+pub fn numbers<T: FromStr>(text: &str) -> impl Iterator<Item = Result<T, Error>> + '_ {
+    let bytes = text.as_bytes();
+    let mut i = 0;
+    std::iter::from_fn(move || {
+        while i < bytes.len() {
+            let start = i;
+            let negative = bytes[i] == b'-' && bytes.get(i + 1).is_some_and(u8::is_ascii_digit);
+            if negative || bytes[i].is_ascii_digit() {
+                i += 1;
+                while i < bytes.len() && bytes[i].is_ascii_digit() {
+                    i += 1;
+                }
+                return Some(parse_number(&text[start..i], "number"));
+            }
+            i += 1;
+        }
+        None
+    })
+}
+
+/// A list of numbers by name, like `cats: 7, trees: 3`.
+pub struct KeyValues<'a, T> {
+    text: &'a str,
+    entries: Vec<(&'a str, T)>,
+}
+
+/// Splits `text` into entries on `separator`, and each entry into its key and
+/// value on `assign`.
+pub fn key_values<'a, T: FromStr>(
+    text: &'a str,
+    separator: &str,
+    assign: &str,
+) -> Result<KeyValues<'a, T>, Error> {
+    let entries = text
+        .split(separator)
+        .map(|entry| {
+            let (key, value) = entry
+                .split_once(assign)
+                .ok_or_else(|| Error::at(entry, format!("Expected <key>{assign}<value>")))?;
+            let key = key.trim();
+            Ok((key, parse_number(value.trim(), key)?))
+        })
+        .collect::<Result<_, Error>>()?;
+    Ok(KeyValues { text, entries })
+}
+
+impl<'a, T: Copy> KeyValues<'a, T> {
+    /// Rejects any key not in `keys`.
+    pub fn restrict_to(self, keys: &[&str]) -> Result<Self, Error> {
+        match self.entries.iter().find(|(key, _)| !keys.contains(key)) {
+            Some((key, _)) => Err(Error::at(
+                key,
+                format!("Expected one of {}", keys.join(", ")),
+            )),
+            None => Ok(self),
+        }
+    }
+
+    pub fn get(&self, key: &str) -> Option<T> {
+        self.entries
+            .iter()
+            .find(|(entry, _)| *entry == key)
+            .map(|&(_, value)| value)
+    }
+
+    pub fn require(&self, key: &str) -> Result<T, Error> {
+        self.get(key)
+            .ok_or_else(|| Error::at(self.text, format!("Missing {key}")))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ROUTE: Pattern = Pattern::new("{from} to {to} = {distance}");
+
+    #[test]
+    fn pattern_captures() {
+        assert_eq!(
+            ROUTE.parse::<(&str, &str, u16)>("London to Dublin = 464"),
+            Ok(("London", "Dublin", 464))
+        );
+        assert_eq!(
+            Pattern::new("{x},{y}.").captures("3,-4."),
+            Ok(vec!["3", "-4"])
+        );
+    }
+
+    #[test]
+    fn pattern_mismatches() {
+        let error = ROUTE
+            .parse::<(&str, &str, u16)>("London - Dublin = 464")
+            .unwrap_err();
+        assert_eq!(error.message(), "Expected \"{from} to {to} = {distance}\"");
+        let error = ROUTE
+            .parse::<(&str, &str, u16)>("London to Dublin = far")
+            .unwrap_err();
+        assert_eq!(
+            (error.message(), error.text()),
+            ("Invalid distance", Some("far"))
+        );
+        assert!(Pattern::new("{x}.").captures("1.2").is_err());
+        assert!(Pattern::new("a {x}").captures("b 1").is_err());
+        assert!(Pattern::new("{x} b").captures("1 bc").is_err());
+    }
+
+    #[test]
+    fn extracts_numbers() {
+        let numbers: Result<Vec<i32>, _> = numbers(r#"{"a":[-1,1],"b-c":22}x-y"#).collect();
+        assert_eq!(numbers, Ok(vec![-1, 1, 22]));
+    }
+
+    #[test]
+    fn key_value_lists() {
+        let values = key_values::<u8>("cats: 7, trees: 3", ", ", ": ").unwrap();
+        assert_eq!(values.get("cats"), Some(7));
+        assert_eq!(values.get("cars"), None);
+        assert!(values.require("cars").is_err());
+        assert!(values.restrict_to(&["cats"]).is_err());
+        assert!(key_values::<u8>("cats 7", ", ", ": ").is_err());
+    }
+}
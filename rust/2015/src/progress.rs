@@ -0,0 +1,235 @@
+//! Progress reports for long searches, written to stderr.
+//!
+//! A [`Progress`] counts work as it is done, from any number of threads, and
+//! now and then reports how much is done, how fast it is going and, when the
+//! total is known, how long is left. How it reports is up to its
+//! [`ProgressStyle`]: a line redrawn in place on a terminal, a plain line every
+//! few seconds for logs, or nothing at all. The style is set once for the whole
+//! process with [`set_style`]; by default it depends on whether stderr is a
+//! terminal.
+
+use std::{
+    io::{self, IsTerminal, Write},
+    sync::{
+        atomic::{AtomicBool, AtomicU64, AtomicU8, Ordering},
+        Mutex,
+    },
+    time::{Duration, Instant},
+};
+
+/// How often a live line is redrawn.
+const LIVE_INTERVAL: Duration = Duration::from_millis(100);
+/// How often a line is logged.
+const LOG_INTERVAL: Duration = Duration::from_secs(5);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProgressStyle {
+    /// One line, redrawn in place; only sensible on a terminal.
+    Live,
+    /// A new line every few seconds, safe to pipe to a file.
+    Log,
+    Silent,
+}
+
+impl ProgressStyle {
+    /// Reads `live`, `log` or `silent`.
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "live" => Some(ProgressStyle::Live),
+            "log" => Some(ProgressStyle::Log),
+            "silent" => Some(ProgressStyle::Silent),
+            _ => None,
+        }
+    }
+}
+
+/// The style set with [`set_style`], as its position in `STYLES` plus one, or
+/// 0 if none was.
+static STYLE: AtomicU8 = AtomicU8::new(0);
+const STYLES: [ProgressStyle; 3] = [
+    ProgressStyle::Live,
+    ProgressStyle::Log,
+    ProgressStyle::Silent,
+];
+
+/// Sets how every [`Progress`] created from now on reports.
+pub fn set_style(style: ProgressStyle) {
+    let index = STYLES.iter().position(|&s| s == style).unwrap();
+    STYLE.store(index as u8 + 1, Ordering::Relaxed);
+}
+
+/// The style set with [`set_style`], or else live on a terminal and logged
+/// otherwise. Tests stay silent unless they set a style.
+pub fn style() -> ProgressStyle {
+    match STYLE.load(Ordering::Relaxed) {
+        0 if cfg!(test) => ProgressStyle::Silent,
+        0 if io::stderr().is_terminal() => ProgressStyle::Live,
+        0 => ProgressStyle::Log,
+        index => STYLES[index as usize - 1],
+    }
+}
+
+pub struct Progress {
+    label: String,
+    style: ProgressStyle,
+    total: Option<u64>,
+    done: AtomicU64,
+    start: Instant,
+    /// When the last report went out, or when the work started.
+    last_report: Mutex<Instant>,
+    /// Whether a live line is on screen and needs clearing.
+    reported: AtomicBool,
+}
+
+impl Progress {
+    /// Progress on work of unknown size, reported in the process's style.
+    pub fn new(label: impl Into<String>) -> Self {
+        let start = Instant::now();
+        Progress {
+            label: label.into(),
+            style: style(),
+            total: None,
+            done: AtomicU64::new(0),
+            start,
+            last_report: Mutex::new(start),
+            reported: AtomicBool::new(false),
+        }
+    }
+
+    /// The amount of work there is, which adds a percentage and an ETA.
+    pub fn total(mut self, total: u64) -> Self {
+        self.total = Some(total);
+        self
+    }
+
+    pub fn style(mut self, style: ProgressStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    pub fn add(&self, amount: u64) {
+        self.add_with(amount, String::new);
+    }
+
+    /// Counts `amount` more work as done, and reports if it's time to, with
+    /// `detail` appended. `detail` is only called when there is a report.
+    pub fn add_with(&self, amount: u64, detail: impl FnOnce() -> String) {
+        let done = self.done.fetch_add(amount, Ordering::Relaxed) + amount;
+        let interval = match self.style {
+            ProgressStyle::Live => LIVE_INTERVAL,
+            ProgressStyle::Log => LOG_INTERVAL,
+            ProgressStyle::Silent => return,
+        };
+        // Whoever holds the lock is already reporting, so nobody has to wait
+        let Ok(mut last_report) = self.last_report.try_lock() else {
+            return;
+        };
+        let now = Instant::now();
+        if now.duration_since(*last_report) < interval {
+            return;
+        }
+        *last_report = now;
+        let line = self.line(done, now.duration_since(self.start), &detail());
+        match self.style {
+            ProgressStyle::Live => {
+                eprint!("\r\x1B[2K{line}");
+                let _ = io::stderr().flush();
+                self.reported.store(true, Ordering::Relaxed);
+            }
+            _ => eprintln!("{line}"),
+        }
+    }
+
+    /// Clears the live line, if there is one.
+    pub fn finish(&self) {
+        if self.style == ProgressStyle::Live && self.reported.load(Ordering::Relaxed) {
+            eprint!("\r\x1B[2K");
+        }
+    }
+
+    /// Replaces the live line with a last report with `detail`, or logs it.
+    pub fn finish_with(&self, detail: impl FnOnce() -> String) {
+        if self.style == ProgressStyle::Silent {
+            return;
+        }
+        self.finish();
+        let done = self.done.load(Ordering::Relaxed);
+        eprintln!("{}", self.line(done, self.start.elapsed(), &detail()));
+    }
+
+    /// E.g. `Mining: 2.50M, 1.20M/s, 40%, ETA 3s, at 2500000`.
+    fn line(&self, done: u64, elapsed: Duration, detail: &str) -> String {
+        let rate = done as f64 / elapsed.as_secs_f64().max(f64::EPSILON);
+        let mut line = format!("{}: {}, {}/s", self.label, count(done as f64), count(rate));
+        if let Some(total) = self.total.filter(|&total| total > 0) {
+            let left = total.saturating_sub(done) as f64 / rate.max(f64::EPSILON);
+            line += &format!(
+                ", {:.0}%, ETA {}",
+                100.0 * done.min(total) as f64 / total as f64,
+                duration(Duration::from_secs_f64(left.min(1e9)))
+            );
+        }
+        if !detail.is_empty() {
+            line += ", ";
+            line += detail;
+        }
+        line
+    }
+}
+
+/// `n` with a k, M or G suffix and two decimals once it's large.
+fn count(n: f64) -> String {
+    match ["", "k", "M", "G"]
+        .iter()
+        .enumerate()
+        .rev()
+        .find(|&(power, _)| n >= 1000f64.powi(power as i32))
+    {
+        Some((0, _)) | None => format!("{n:.0}"),
+        Some((power, suffix)) => format!("{:.2}{suffix}", n / 1000f64.powi(power as i32)),
+    }
+}
+
+/// `d` to the second, in the two largest units it needs.
+fn duration(d: Duration) -> String {
+    let seconds = d.as_secs();
+    match seconds {
+        0..60 => format!("{seconds}s"),
+        60..3600 => format!("{}m {:02}s", seconds / 60, seconds % 60),
+        _ => format!("{}h {:02}m", seconds / 3600, seconds % 3600 / 60),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn report_lines() {
+        let progress = Progress::new("Mining").total(10_000_000);
+        assert_eq!(
+            progress.line(2_500_000, Duration::from_secs(2), "at 2500000"),
+            "Mining: 2.50M, 1.25M/s, 25%, ETA 6s, at 2500000"
+        );
+        let progress = Progress::new("Searching");
+        assert_eq!(
+            progress.line(999, Duration::from_millis(500), ""),
+            "Searching: 999, 2.00k/s"
+        );
+    }
+
+    #[test]
+    fn durations_and_counts() {
+        assert_eq!(duration(Duration::from_secs(59)), "59s");
+        assert_eq!(duration(Duration::from_secs(65)), "1m 05s");
+        assert_eq!(duration(Duration::from_secs(3 * 3600 + 120)), "3h 02m");
+        assert_eq!(count(0.0), "0");
+        assert_eq!(count(12_500.0), "12.50k");
+    }
+
+    #[test]
+    fn styles_by_name() {
+        assert_eq!(ProgressStyle::from_name("log"), Some(ProgressStyle::Log));
+        assert_eq!(ProgressStyle::from_name("loud"), None);
+    }
+}
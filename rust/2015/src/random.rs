@@ -0,0 +1,162 @@
+//! A small seeded random number generator for the input generators, so the
+//! same seed always gives the same input without pulling in a crate.
+//!
+//! It is SplitMix64: fast and statistically sound enough for puzzle inputs, and
+//! nowhere near cryptographic.
+
+use std::{collections::HashSet, ops::RangeInclusive};
+
+#[derive(Debug, Clone)]
+pub struct Rng {
+    state: u64,
+}
+
+const NAME_CONSONANTS: &[u8] = b"bdfghklmnprstvz";
+const NAME_VOWELS: &[u8] = b"aeiou";
+
+impl Rng {
+    /// How many different names [`Rng::names`] can make: one for each pick of
+    /// a consonant and a vowel for two or three syllables.
+    pub const MAX_NAMES: usize = {
+        let syllables = NAME_CONSONANTS.len() * NAME_VOWELS.len();
+        syllables.pow(2) + syllables.pow(3)
+    };
+
+    pub fn new(seed: u64) -> Self {
+        Rng { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// A number in `0..bound`, for a `bound` of at most 2^64.
+    fn below(&mut self, bound: u128) -> u128 {
+        (self.next_u64() as u128 * bound) >> 64
+    }
+
+    /// A number in `range`, every one equally likely.
+    pub fn range<T: Integer>(&mut self, range: RangeInclusive<T>) -> T {
+        let (low, high) = (range.start().to_i128(), range.end().to_i128());
+        assert!(low <= high, "Empty range {low}..={high}");
+        T::from_i128(low + self.below((high - low + 1) as u128) as i128)
+    }
+
+    /// True `numerator` times out of `denominator`.
+    pub fn chance(&mut self, numerator: u32, denominator: u32) -> bool {
+        self.below(denominator.into()) < numerator.into()
+    }
+
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        assert!(!items.is_empty(), "Nothing to choose from");
+        &items[self.range(0..=items.len() - 1)]
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.range(0..=i));
+        }
+    }
+
+    /// A lowercase ASCII letter.
+    pub fn letter(&mut self) -> char {
+        self.range(b'a'..=b'z') as char
+    }
+
+    /// `count` different capitalised names made of two or three syllables,
+    /// like `Tavoni`. Panics if `count` is more than [`Rng::MAX_NAMES`], since
+    /// it would never find that many.
+    pub fn names(&mut self, count: usize) -> Vec<String> {
+        assert!(
+            count <= Self::MAX_NAMES,
+            "Only {} different names can be made, not {count}",
+            Self::MAX_NAMES
+        );
+        let mut seen = HashSet::new();
+        let mut names = Vec::with_capacity(count);
+        while names.len() < count {
+            let syllables = self.range(2..=3);
+            let mut name: String = (0..syllables)
+                .flat_map(|_| [*self.choose(NAME_CONSONANTS), *self.choose(NAME_VOWELS)])
+                .map(char::from)
+                .collect();
+            name[..1].make_ascii_uppercase();
+            if seen.insert(name.clone()) {
+                names.push(name);
+            }
+        }
+        names
+    }
+}
+
+/// An integer type [`Rng::range`] can pick from.
+pub trait Integer: Copy {
+    fn to_i128(self) -> i128;
+    fn from_i128(value: i128) -> Self;
+}
+
+macro_rules! integers {
+    ($($integer:ty),*) => {
+        $(
+            impl Integer for $integer {
+                fn to_i128(self) -> i128 {
+                    self as i128
+                }
+
+                fn from_i128(value: i128) -> Self {
+                    value as $integer
+                }
+            }
+        )*
+    };
+}
+
+integers!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn same_seed_same_numbers() {
+        let numbers = |seed| {
+            let mut rng = Rng::new(seed);
+            (0..5).map(|_| rng.next_u64()).collect::<Vec<_>>()
+        };
+        assert_eq!(numbers(7), numbers(7));
+        assert_ne!(numbers(7), numbers(8));
+    }
+
+    #[test]
+    fn ranges_cover_both_ends() {
+        let mut rng = Rng::new(1);
+        let picks: HashSet<i8> = (0..200).map(|_| rng.range(-2..=2)).collect();
+        assert_eq!(picks, HashSet::from([-2, -1, 0, 1, 2]));
+        assert_eq!(rng.range(u64::MAX..=u64::MAX), u64::MAX);
+        rng.range(0..=u64::MAX);
+    }
+
+    #[test]
+    fn shuffles_and_names() {
+        let mut rng = Rng::new(2);
+        let mut items: Vec<u32> = (0..20).collect();
+        rng.shuffle(&mut items);
+        assert_ne!(items, (0..20).collect::<Vec<_>>());
+        items.sort();
+        assert_eq!(items, (0..20).collect::<Vec<_>>());
+
+        let names = rng.names(50);
+        assert_eq!(names.iter().collect::<HashSet<_>>().len(), 50);
+        assert!(names[0].starts_with(|c: char| c.is_ascii_uppercase()));
+    }
+
+    #[test]
+    #[should_panic(expected = "Only 427500 different names can be made, not 427501")]
+    fn names_run_out() {
+        Rng::new(3).names(Rng::MAX_NAMES + 1);
+    }
+}
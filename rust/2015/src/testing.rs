@@ -0,0 +1,28 @@
+//! Helpers shared by the per-day tests.
+
+use crate::{
+    input::{InputSource, PuzzleInput},
+    Solution, YEAR,
+};
+
+pub fn part_1<S: Solution>(solution: &S, input: &str) -> S::Output1 {
+    let input = PuzzleInput::new(input);
+    let input = solution.parse(&input).unwrap();
+    solution.part_1(&input)
+}
+
+pub fn part_2<S: Solution>(solution: &S, input: &str) -> S::Output2 {
+    let input = PuzzleInput::new(input);
+    let input = solution.parse(&input).unwrap();
+    solution.part_2(&input)
+}
+
+/// Both answers for our own puzzle input, as the runner prints them.
+pub fn answers<S: Solution>(solution: &S, day: u8) -> [String; 2] {
+    let input = PuzzleInput::load(YEAR, day, S::INPUT_FILES, &InputSource::Default).unwrap();
+    let input = solution.parse(&input).unwrap();
+    [
+        solution.part_1(&input).to_string(),
+        solution.part_2(&input).to_string(),
+    ]
+}
@@ -0,0 +1,395 @@
+use advent_of_code_2015::{input::InputSource, progress::ProgressStyle, Part};
+use std::path::PathBuf;
+
+pub const USAGE: &str = "\
+Usage: aoc <year> <days> [parts] [--input <path> | --generate <size> [--seed <n>]]
+           [--set <name>=<value>]... [--progress <style>]
+           [--json | --verify | --bench [bench options] | --print-input | --parity]
+
+Arguments:
+  <year>          Puzzle year, e.g. 2015
+  <days>          `all`, a day (`7`), a range (`1-5`) or a list (`1,3,10-12`)
+  [parts]         `1`, `2` or `both` (default)
+
+Options:
+  --input <path>  Read the primary input file from <path>, or from stdin if <path> is `-`,
+                  instead of inputs/<year>/day_<NN>/ (only valid for a single day)
+  --generate <size>
+                  Run on a random input from each day's generator instead. What <size>
+                  counts depends on the day (wires for day 7, cities for day 9, the grid
+                  side for day 18, ...); `puzzle` gives an input about as large as ours
+  --seed <n>      Seed for --generate (default 0); the same seed and size always give
+                  the same input
+  --print-input   Print the generated input instead of solving it
+  --set <name>=<value>
+                  Change one of the day's puzzle parameters, such as day 14's `seconds`
+                  or day 22's `hit_points` and `mana` (only valid for a single day)
+  --progress <style>
+                  How long searches (days 4, 20 and 22) report progress on stderr: `live`
+                  (one line redrawn in place), `log` (a line every few seconds) or
+                  `silent`; the default is live on a terminal and log otherwise
+  --json          Print one JSON object per answer and line instead of text:
+                  {year, day, part, answer, duration} with the duration in nanoseconds,
+                  plus a `details` object for days that explain their answers
+  --verify        Check each answer against inputs/<year>/day_<NN>/answers.txt and
+                  exit with an error if any of them differ
+  --bench         Time parsing and each part instead of printing the answers
+  --parity        Also run the Deno solution of each day solved in both languages on the
+                  same input and compare the answers and timings (runs `deno`, or the
+                  program in the DENO environment variable)
+
+Bench options:
+  --runs <n>              Run every day <n> times and report min/median/max (default 1)
+  --baseline <path>       Compare the medians with a baseline saved earlier
+  --save-baseline <path>  Save the medians as a baseline";
+
+pub enum Days {
+    All,
+    List(Vec<u8>),
+}
+
+pub struct BenchOptions {
+    pub runs: usize,
+    pub baseline: Option<PathBuf>,
+    pub save_baseline: Option<PathBuf>,
+}
+
+pub enum Format {
+    Text,
+    Json,
+}
+
+pub enum Mode {
+    Solve(Format),
+    Verify,
+    Bench(BenchOptions),
+    PrintInput,
+    Parity,
+}
+
+/// Where each day's input comes from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Input {
+    Source(InputSource),
+    Generated {
+        /// `None` for an input about as large as the puzzle's.
+        size: Option<usize>,
+        seed: u64,
+    },
+}
+
+pub struct Args {
+    pub year: u16,
+    pub days: Days,
+    pub parts: Vec<Part>,
+    pub input: Input,
+    /// `--set` parameters as `(name, value)`, in order.
+    pub parameters: Vec<(String, String)>,
+    /// `None` to leave it up to the library.
+    pub progress: Option<ProgressStyle>,
+    pub mode: Mode,
+}
+
+impl Args {
+    pub fn parse(args: impl IntoIterator<Item = String>) -> Result<Self, String> {
+        let mut positional = Vec::new();
+        let mut source = InputSource::Default;
+        let mut size = None;
+        let mut seed = None;
+        let mut print_input = false;
+        let mut parameters = Vec::new();
+        let mut progress = None;
+        let mut json = false;
+        let mut verify = false;
+        let mut bench = false;
+        let mut parity = false;
+        let mut runs = None;
+        let mut baseline = None;
+        let mut save_baseline = None;
+
+        let mut args = args.into_iter();
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--input" | "-i" => {
+                    source = InputSource::from_arg(&args.next().ok_or("--input requires a path")?);
+                }
+                "--generate" => {
+                    let n = args.next().ok_or("--generate requires a size")?;
+                    size = Some(match n.as_str() {
+                        "puzzle" => None,
+                        _ => Some(
+                            n.parse::<usize>()
+                                .map_err(|_| format!("Invalid input size: {n}"))?,
+                        ),
+                    });
+                }
+                "--seed" => {
+                    let n = args.next().ok_or("--seed requires a number")?;
+                    seed = Some(n.parse::<u64>().map_err(|_| format!("Invalid seed: {n}"))?);
+                }
+                "--set" => {
+                    let parameter = args.next().ok_or("--set requires <name>=<value>")?;
+                    let (name, value) = parameter.split_once('=').ok_or_else(|| {
+                        format!("Invalid parameter: {parameter}, expected <name>=<value>")
+                    })?;
+                    parameters.push((name.to_string(), value.to_string()));
+                }
+                "--progress" => {
+                    let style = args.next().ok_or("--progress requires a style")?;
+                    progress = Some(ProgressStyle::from_name(&style).ok_or_else(|| {
+                        format!("Invalid progress style: {style}, expected live, log or silent")
+                    })?);
+                }
+                "--print-input" => print_input = true,
+                "--json" => json = true,
+                "--verify" => verify = true,
+                "--bench" => bench = true,
+                "--parity" => parity = true,
+                "--runs" => {
+                    let n = args.next().ok_or("--runs requires a number")?;
+                    runs = Some(
+                        n.parse::<usize>()
+                            .ok()
+                            .filter(|&n| n > 0)
+                            .ok_or_else(|| format!("Invalid number of runs: {n}"))?,
+                    );
+                }
+                "--baseline" => {
+                    baseline = Some(PathBuf::from(
+                        args.next().ok_or("--baseline requires a path")?,
+                    ));
+                }
+                "--save-baseline" => {
+                    save_baseline = Some(PathBuf::from(
+                        args.next().ok_or("--save-baseline requires a path")?,
+                    ));
+                }
+                flag if flag.starts_with("--") => return Err(format!("Unknown option: {flag}")),
+                _ => positional.push(arg),
+            }
+        }
+
+        let mut positional = positional.into_iter();
+        let year = positional.next().ok_or("Missing <year>")?;
+        let year = year
+            .parse::<u16>()
+            .map_err(|_| format!("Invalid year: {year}"))?;
+        let days = parse_days(&positional.next().ok_or("Missing <days>")?)?;
+        let parts = match positional.next() {
+            Some(parts) => parse_parts(&parts)?,
+            None => Part::BOTH.to_vec(),
+        };
+        if let Some(extra) = positional.next() {
+            return Err(format!("Unexpected argument: {extra}"));
+        }
+        let input = match size {
+            Some(_) if source != InputSource::Default => {
+                return Err("--input and --generate cannot be used together".to_string())
+            }
+            Some(size) => Input::Generated {
+                size,
+                seed: seed.unwrap_or(0),
+            },
+            None if seed.is_some() || print_input => {
+                return Err("--seed and --print-input can only be used with --generate".to_string())
+            }
+            None => Input::Source(source),
+        };
+        if verify && input != Input::Source(InputSource::Default) {
+            return Err(
+                "--verify checks the recorded inputs, so it cannot be used with --input or --generate"
+                    .to_string(),
+            );
+        }
+        if verify && !parameters.is_empty() {
+            return Err(
+                "--verify checks the puzzle's own answers, so it cannot be used with --set"
+                    .to_string(),
+            );
+        }
+        if !bench && (runs.is_some() || baseline.is_some() || save_baseline.is_some()) {
+            return Err(
+                "--runs, --baseline and --save-baseline can only be used with --bench".to_string(),
+            );
+        }
+        if json && (verify || bench) {
+            return Err(
+                "--json can only be used when solving, not with --verify or --bench".to_string(),
+            );
+        }
+        if print_input && (json || verify || bench) {
+            return Err(
+                "--print-input cannot be used with --json, --verify or --bench".to_string(),
+            );
+        }
+        if parity && (json || verify || bench || print_input) {
+            return Err(
+                "--parity cannot be used with --json, --verify, --bench or --print-input"
+                    .to_string(),
+            );
+        }
+        let mode = match (verify, bench) {
+            (true, true) => return Err("--verify and --bench cannot be used together".to_string()),
+            (true, false) => Mode::Verify,
+            (false, true) => Mode::Bench(BenchOptions {
+                runs: runs.unwrap_or(1),
+                baseline,
+                save_baseline,
+            }),
+            (false, false) if print_input => Mode::PrintInput,
+            (false, false) if parity => Mode::Parity,
+            (false, false) if json => Mode::Solve(Format::Json),
+            (false, false) => Mode::Solve(Format::Text),
+        };
+
+        Ok(Args {
+            year,
+            days,
+            parts,
+            input,
+            parameters,
+            progress,
+            mode,
+        })
+    }
+}
+
+fn parse_day(day: &str) -> Result<u8, String> {
+    day.parse::<u8>()
+        .ok()
+        .filter(|day| (1..=25).contains(day))
+        .ok_or_else(|| format!("Invalid day: {day}"))
+}
+
+fn parse_days(days: &str) -> Result<Days, String> {
+    if days == "all" {
+        return Ok(Days::All);
+    }
+
+    let mut list = Vec::new();
+    for item in days.split(',') {
+        match item.split_once('-') {
+            Some((from, to)) => {
+                let (from, to) = (parse_day(from)?, parse_day(to)?);
+                if from > to {
+                    return Err(format!("Invalid day range: {item}"));
+                }
+                list.extend(from..=to);
+            }
+            None => list.push(parse_day(item)?),
+        }
+    }
+    list.sort();
+    list.dedup();
+    Ok(Days::List(list))
+}
+
+fn parse_parts(parts: &str) -> Result<Vec<Part>, String> {
+    match parts {
+        "1" => Ok(vec![Part::One]),
+        "2" => Ok(vec![Part::Two]),
+        "both" => Ok(Part::BOTH.to_vec()),
+        _ => Err(format!("Invalid part: {parts}")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(args: &str) -> Result<Args, String> {
+        Args::parse(args.split_whitespace().map(String::from))
+    }
+
+    fn days(args: &str) -> Vec<u8> {
+        match parse(args).unwrap().days {
+            Days::List(days) => days,
+            Days::All => panic!("Expected a list of days"),
+        }
+    }
+
+    #[test]
+    fn days_and_parts() {
+        assert!(matches!(parse("2015 all").unwrap().days, Days::All));
+        assert_eq!(days("2015 7"), [7]);
+        assert_eq!(days("2015 9,1-5,3"), [1, 2, 3, 4, 5, 9]);
+        assert_eq!(days("2015 25-25"), [25]);
+        assert_eq!(parse("2015 7 2").unwrap().parts, [Part::Two]);
+        assert_eq!(parse("2015 7").unwrap().parts, Part::BOTH);
+
+        let error = |args| parse(args).err().unwrap();
+        assert_eq!(error("2015 5-3"), "Invalid day range: 5-3");
+        assert_eq!(error("2015 0"), "Invalid day: 0");
+        assert_eq!(error("2015 1-26"), "Invalid day: 26");
+        assert_eq!(error("2015 1,x"), "Invalid day: x");
+        assert_eq!(error("2015 7 3"), "Invalid part: 3");
+        assert_eq!(error("2015"), "Missing <days>");
+        assert_eq!(error("2015 7 1 extra"), "Unexpected argument: extra");
+    }
+
+    #[test]
+    fn inputs_and_modes() {
+        let args = parse("2015 3 --generate 50 --seed 7 --set turns=round-robin:3").unwrap();
+        assert_eq!(
+            args.input,
+            Input::Generated {
+                size: Some(50),
+                seed: 7
+            }
+        );
+        assert_eq!(
+            args.parameters,
+            [("turns".to_string(), "round-robin:3".to_string())]
+        );
+        assert!(matches!(args.mode, Mode::Solve(Format::Text)));
+
+        let args = parse("2015 all --bench --runs 3 --baseline old.txt").unwrap();
+        let Mode::Bench(options) = args.mode else {
+            panic!("Expected --bench");
+        };
+        assert_eq!(options.runs, 3);
+        assert_eq!(options.baseline, Some(PathBuf::from("old.txt")));
+        assert_eq!(
+            parse("2015 1 --input -").unwrap().input,
+            Input::Source(InputSource::Stdin)
+        );
+    }
+
+    #[test]
+    fn conflicting_flags() {
+        let error = |args| parse(args).err().unwrap();
+        assert_eq!(
+            error("2015 1 --input a.txt --generate 5"),
+            "--input and --generate cannot be used together"
+        );
+        assert_eq!(
+            error("2015 1 --seed 3"),
+            "--seed and --print-input can only be used with --generate"
+        );
+        assert_eq!(
+            error("2015 all --verify --bench"),
+            "--verify and --bench cannot be used together"
+        );
+        assert_eq!(
+            error("2015 1 --verify --set seconds=5"),
+            "--verify checks the puzzle's own answers, so it cannot be used with --set"
+        );
+        assert_eq!(
+            error("2015 all --runs 5"),
+            "--runs, --baseline and --save-baseline can only be used with --bench"
+        );
+        assert_eq!(
+            error("2015 all --json --bench"),
+            "--json can only be used when solving, not with --verify or --bench"
+        );
+        assert_eq!(
+            error("2015 all --parity --json"),
+            "--parity cannot be used with --json, --verify, --bench or --print-input"
+        );
+        assert_eq!(
+            error("2015 all --bench --runs 0"),
+            "Invalid number of runs: 0"
+        );
+        assert_eq!(error("2015 all --fast"), "Unknown option: --fast");
+    }
+}
@@ -0,0 +1,371 @@
+//! `--bench`: times parsing and each part of every day over repeated runs and
+//! prints the results as a table, optionally against a saved baseline.
+//!
+//! A baseline file is plain text with one stage per line,
+//! `<year> <day> <stage> <median in ns>`, where the stage is `parse`, `part1` or
+//! `part2`. Lines starting with `#` are ignored.
+
+use crate::args::{BenchOptions, Input};
+use advent_of_code_2015::{Error, Part};
+use std::{
+    collections::HashMap,
+    fmt::{self, Display},
+    fs, io,
+    path::Path,
+    time::Duration,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum Stage {
+    Parse,
+    Solve(Part),
+}
+
+impl Stage {
+    fn key(self) -> &'static str {
+        match self {
+            Stage::Parse => "parse",
+            Stage::Solve(Part::One) => "part1",
+            Stage::Solve(Part::Two) => "part2",
+        }
+    }
+
+    fn from_key(key: &str) -> Option<Self> {
+        match key {
+            "parse" => Some(Stage::Parse),
+            "part1" => Some(Stage::Solve(Part::One)),
+            "part2" => Some(Stage::Solve(Part::Two)),
+            _ => None,
+        }
+    }
+}
+
+impl Display for Stage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Stage::Parse => write!(f, "Parse"),
+            Stage::Solve(part) => write!(f, "{part}"),
+        }
+    }
+}
+
+/// Every run's time for one stage of one day.
+struct Measurement {
+    day: u8,
+    stage: Stage,
+    runs: Vec<Duration>,
+}
+
+impl Measurement {
+    fn min(&self) -> Duration {
+        self.runs.iter().copied().min().unwrap_or_default()
+    }
+
+    fn max(&self) -> Duration {
+        self.runs.iter().copied().max().unwrap_or_default()
+    }
+
+    fn median(&self) -> Duration {
+        let mut runs = self.runs.clone();
+        runs.sort();
+        match runs.len() {
+            0 => Duration::ZERO,
+            n if n % 2 == 1 => runs[n / 2],
+            n => (runs[n / 2 - 1] + runs[n / 2]) / 2,
+        }
+    }
+}
+
+type Baseline = HashMap<(u16, u8, Stage), Duration>;
+
+pub fn run(
+    year: u16,
+    days: &[u8],
+    parts: &[Part],
+    input: &Input,
+    parameters: &[(String, String)],
+    options: &BenchOptions,
+) -> Result<(), Error> {
+    let baseline = match &options.baseline {
+        Some(path) => Some(
+            read_baseline(path)?
+                .ok_or_else(|| Error::new(format!("Baseline {} does not exist", path.display())))?,
+        ),
+        None => None,
+    };
+
+    println!(
+        "{year}, {} run{} per day\n",
+        options.runs,
+        if options.runs == 1 { "" } else { "s" }
+    );
+    print_header(baseline.is_some());
+
+    let mut measurements = Vec::new();
+    for &day in days {
+        let (solution, input) = crate::load(year, day, input, parameters)?;
+
+        let mut day_measurements: Vec<Measurement> = [Stage::Parse]
+            .into_iter()
+            .chain(parts.iter().map(|&part| Stage::Solve(part)))
+            .map(|stage| Measurement {
+                day,
+                stage,
+                runs: Vec::with_capacity(options.runs),
+            })
+            .collect();
+        for _ in 0..options.runs {
+            let timings = solution.time(&input, parts)?;
+            day_measurements[0].runs.push(timings.parse);
+            for (measurement, (_, duration)) in day_measurements[1..].iter_mut().zip(timings.parts)
+            {
+                measurement.runs.push(duration);
+            }
+        }
+
+        for measurement in &day_measurements {
+            let previous = baseline
+                .as_ref()
+                .map(|baseline| baseline.get(&(year, day, measurement.stage)).copied());
+            print_row(
+                &format!("{day:>3}"),
+                measurement.stage,
+                [measurement.min(), measurement.median(), measurement.max()].map(Some),
+                previous,
+            );
+        }
+        measurements.extend(day_measurements);
+    }
+
+    let total: Duration = measurements.iter().map(Measurement::median).sum();
+    let previous_total = baseline.as_ref().map(|baseline| {
+        measurements
+            .iter()
+            .map(|m| baseline.get(&(year, m.day, m.stage)).copied())
+            .sum::<Option<Duration>>()
+    });
+    print_row("Sum", "medians", [None, Some(total), None], previous_total);
+
+    if let Some(path) = &options.save_baseline {
+        save_baseline(path, year, &measurements, options.runs)?;
+        println!("\nSaved baseline to {}", path.display());
+    }
+
+    Ok(())
+}
+
+fn print_header(with_baseline: bool) {
+    print!(
+        "{:>3}  {:<8} {:>10} {:>10} {:>10}",
+        "Day", "Stage", "Min", "Median", "Max"
+    );
+    if with_baseline {
+        print!(" {:>10} {:>8}", "Baseline", "Change");
+    }
+    println!();
+}
+
+/// One table row. `previous` is `None` without a baseline, and `Some(None)`
+/// when the baseline has no entry for this row.
+fn print_row(
+    day: &str,
+    stage: impl Display,
+    [min, median, max]: [Option<Duration>; 3],
+    previous: Option<Option<Duration>>,
+) {
+    let cell = |duration: Option<Duration>| duration.map(format_duration).unwrap_or_default();
+    print!(
+        "{day:>3}  {:<8} {:>10} {:>10} {:>10}",
+        stage.to_string(),
+        cell(min),
+        cell(median),
+        cell(max)
+    );
+    match (previous, median) {
+        (Some(Some(previous)), Some(median)) => print!(
+            " {:>10} {:>8}",
+            format_duration(previous),
+            format_change(previous, median)
+        ),
+        (Some(None), _) => print!(" {:>10} {:>8}", "-", "-"),
+        _ => {}
+    }
+    println!();
+}
+
+pub fn format_duration(duration: Duration) -> String {
+    match duration.as_nanos() {
+        nanos @ 0..1_000 => format!("{nanos}ns"),
+        nanos @ 1_000..1_000_000 => format!("{:.1}µs", nanos as f64 / 1e3),
+        nanos @ 1_000_000..1_000_000_000 => format!("{:.2}ms", nanos as f64 / 1e6),
+        nanos => format!("{:.3}s", nanos as f64 / 1e9),
+    }
+}
+
+fn format_change(previous: Duration, current: Duration) -> String {
+    if previous.is_zero() {
+        return "-".to_string();
+    }
+    let change = (current.as_secs_f64() / previous.as_secs_f64() - 1.0) * 100.0;
+    format!("{change:+.1}%")
+}
+
+/// Reads a baseline file, or `None` if there isn't one at `path`.
+fn read_baseline(path: &Path) -> Result<Option<Baseline>, Error> {
+    let text = match fs::read_to_string(path) {
+        Ok(text) => text,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(None),
+        Err(e) => {
+            return Err(Error::new(format!(
+                "Could not read {}: {e}",
+                path.display()
+            )))
+        }
+    };
+
+    let mut baseline = Baseline::new();
+    for (i, line) in text.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let invalid = || {
+            Error::new(format!(
+                "{}:{}: invalid baseline entry: {line}",
+                path.display(),
+                i + 1
+            ))
+        };
+        let fields: Vec<&str> = line.split_whitespace().collect();
+        let [year, day, stage, nanos] = fields[..] else {
+            return Err(invalid());
+        };
+        baseline.insert(
+            (
+                year.parse().map_err(|_| invalid())?,
+                day.parse().map_err(|_| invalid())?,
+                Stage::from_key(stage).ok_or_else(invalid)?,
+            ),
+            Duration::from_nanos(nanos.parse().map_err(|_| invalid())?),
+        );
+    }
+    Ok(Some(baseline))
+}
+
+/// Writes the medians to `path`, keeping whatever an existing baseline there
+/// recorded for days and parts that were not benchmarked this time.
+fn save_baseline(
+    path: &Path,
+    year: u16,
+    measurements: &[Measurement],
+    runs: usize,
+) -> Result<(), Error> {
+    let mut baseline = read_baseline(path)?.unwrap_or_default();
+    for measurement in measurements {
+        baseline.insert(
+            (year, measurement.day, measurement.stage),
+            measurement.median(),
+        );
+    }
+
+    let mut entries: Vec<_> = baseline.into_iter().collect();
+    entries.sort_by_key(|&((year, day, stage), _)| (year, day, stage.key()));
+
+    let mut text = format!(
+        "# aoc --bench baseline: <year> <day> <stage> <median in ns>, \
+         last saved from {runs} run(s)\n"
+    );
+    for ((year, day, stage), median) in entries {
+        text.push_str(&format!(
+            "{year} {day} {} {}\n",
+            stage.key(),
+            median.as_nanos()
+        ));
+    }
+    fs::write(path, text)
+        .map_err(|e| Error::new(format!("Could not write {}: {e}", path.display())))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{env, path::PathBuf, process};
+
+    /// A file in the temporary directory that is removed when dropped.
+    struct TempFile(PathBuf);
+
+    impl TempFile {
+        fn new(name: &str) -> Self {
+            TempFile(env::temp_dir().join(format!("aoc_{name}_{}.txt", process::id())))
+        }
+    }
+
+    impl Drop for TempFile {
+        fn drop(&mut self) {
+            let _ = fs::remove_file(&self.0);
+        }
+    }
+
+    fn measurement(day: u8, stage: Stage, runs: &[u64]) -> Measurement {
+        Measurement {
+            day,
+            stage,
+            runs: runs.iter().map(|&ms| Duration::from_millis(ms)).collect(),
+        }
+    }
+
+    #[test]
+    fn baselines_round_trip() {
+        let file = TempFile::new("baseline");
+        assert_eq!(read_baseline(&file.0).unwrap(), None);
+
+        fs::write(&file.0, "# old\n2015 1 parse 5\n2015 2 part2 7000\n").unwrap();
+        let measurements = [
+            measurement(2, Stage::Parse, &[3, 1, 2]),
+            measurement(2, Stage::Solve(Part::Two), &[4, 6]),
+        ];
+        save_baseline(&file.0, 2015, &measurements, 3).unwrap();
+
+        let baseline = read_baseline(&file.0).unwrap().unwrap();
+        assert_eq!(baseline.len(), 3);
+        // Days that weren't benchmarked keep their old medians
+        assert_eq!(baseline[&(2015, 1, Stage::Parse)], Duration::from_nanos(5));
+        assert_eq!(baseline[&(2015, 2, Stage::Parse)], Duration::from_millis(2));
+        assert_eq!(
+            baseline[&(2015, 2, Stage::Solve(Part::Two))],
+            Duration::from_millis(5)
+        );
+    }
+
+    #[test]
+    fn invalid_baselines() {
+        let file = TempFile::new("invalid_baseline");
+        fs::write(&file.0, "2015 1 parse 5\n2015 1 part3 5\n").unwrap();
+        let error = read_baseline(&file.0).unwrap_err();
+        assert_eq!(
+            error.message(),
+            format!(
+                "{}:2: invalid baseline entry: 2015 1 part3 5",
+                file.0.display()
+            )
+        );
+    }
+
+    #[test]
+    fn medians_and_changes() {
+        assert_eq!(
+            measurement(1, Stage::Parse, &[3, 1, 2]).median(),
+            Duration::from_millis(2)
+        );
+        assert_eq!(
+            measurement(1, Stage::Parse, &[4, 1, 2, 3]).median(),
+            Duration::from_micros(2500)
+        );
+        assert_eq!(
+            format_change(Duration::from_millis(200), Duration::from_millis(150)),
+            "-25.0%"
+        );
+        assert_eq!(format_change(Duration::ZERO, Duration::from_millis(1)), "-");
+        assert_eq!(format_duration(Duration::from_micros(2500)), "2.50ms");
+    }
+}
@@ -0,0 +1,189 @@
+mod args;
+mod bench;
+mod parity;
+mod years;
+
+use advent_of_code_2015::{
+    input::{recorded_answers, InputSource, PuzzleInput},
+    progress, Answer, DynSolution, Error, Part,
+};
+use args::{Args, Days, Format, Input, Mode, USAGE};
+use serde_json::json;
+use std::{env, process::ExitCode};
+
+fn run(args: &Args) -> Result<(), Error> {
+    let days = match &args.days {
+        Days::All => years::days(args.year),
+        Days::List(days) => days.clone(),
+    };
+    if days.is_empty() {
+        return Err(Error::new(format!("No solutions for {}", args.year)));
+    }
+    if matches!(&args.input, Input::Source(source) if *source != InputSource::Default)
+        && days.len() != 1
+    {
+        return Err(Error::new("--input can only be used with a single day"));
+    }
+    if !args.parameters.is_empty() && days.len() != 1 {
+        return Err(Error::new("--set can only be used with a single day"));
+    }
+
+    match &args.mode {
+        Mode::Solve(format) => {
+            for day in days {
+                for answer in solve(args.year, day, &args.parts, &args.input, &args.parameters)? {
+                    match format {
+                        Format::Text => {
+                            println!(
+                                "[{}-{day:02}] {}: {}",
+                                args.year, answer.part, answer.answer
+                            )
+                        }
+                        Format::Json => println!("{}", to_json(args.year, day, answer)),
+                    }
+                }
+            }
+            Ok(())
+        }
+        Mode::Verify => verify(args.year, &days, &args.parts),
+        Mode::Bench(options) => bench::run(
+            args.year,
+            &days,
+            &args.parts,
+            &args.input,
+            &args.parameters,
+            options,
+        ),
+        Mode::Parity => parity::run(args.year, &days, &args.parts, &args.input, &args.parameters),
+        Mode::PrintInput => {
+            for day in days {
+                let (_, input) = load(args.year, day, &args.input, &args.parameters)?;
+                let files: Vec<_> = input.files().collect();
+                for (i, (name, text)) in files.iter().enumerate() {
+                    if files.len() > 1 {
+                        let gap = if i == 0 { "" } else { "\n" };
+                        println!("{gap}==> {name}.txt <==");
+                    }
+                    println!("{text}");
+                }
+            }
+            Ok(())
+        }
+    }
+}
+
+/// A day's solution with its parameters set, and the input to run it on.
+pub fn load(
+    year: u16,
+    day: u8,
+    input: &Input,
+    parameters: &[(String, String)],
+) -> Result<(Box<dyn DynSolution>, PuzzleInput), Error> {
+    let solution = years::solution(year, day)
+        .ok_or_else(|| Error::new(format!("No solution for {year} day {day}")))?
+        .configured(parameters)?;
+    let input = match input {
+        Input::Source(source) => PuzzleInput::load(year, day, solution.input_files(), source)?,
+        Input::Generated { size, seed } => {
+            solution.generate_input(*seed, size.unwrap_or_else(|| solution.puzzle_size()))
+        }
+    };
+    Ok((solution, input))
+}
+
+fn solve(
+    year: u16,
+    day: u8,
+    parts: &[Part],
+    input: &Input,
+    parameters: &[(String, String)],
+) -> Result<Vec<Answer>, Error> {
+    let (solution, input) = load(year, day, input, parameters)?;
+    solution.answers(&input, parts)
+}
+
+/// The `--json` record for one answer, with `details` left out when the day
+/// has none.
+fn to_json(year: u16, day: u8, answer: Answer) -> serde_json::Value {
+    let mut record = json!({
+        "year": year,
+        "day": day,
+        "part": answer.part.number(),
+        "answer": answer.answer,
+        "duration": answer.duration.as_nanos() as u64,
+    });
+    if let Some(details) = answer.details {
+        record["details"] = details;
+    }
+    record
+}
+
+/// Runs every day against its own input and compares each answer with the
+/// one recorded in `answers.txt`. A day that fails to run counts as failed.
+fn verify(year: u16, days: &[u8], parts: &[Part]) -> Result<(), Error> {
+    let (mut passed, mut failed, mut missing) = (0, 0, 0);
+
+    for &day in days {
+        let recorded = recorded_answers(year, day)?;
+        let answers = match solve(year, day, parts, &Input::Source(InputSource::Default), &[]) {
+            Ok(answers) => answers,
+            Err(e) => {
+                println!("[{year}-{day:02}] fail: {e}");
+                failed += parts.len();
+                continue;
+            }
+        };
+
+        for Answer { part, answer, .. } in answers {
+            match recorded
+                .iter()
+                .find(|(recorded_part, _)| *recorded_part == part)
+            {
+                Some((_, expected)) if *expected == answer => {
+                    passed += 1;
+                    println!("[{year}-{day:02}] {part}: pass {answer}");
+                }
+                Some((_, expected)) => {
+                    failed += 1;
+                    println!("[{year}-{day:02}] {part}: fail {answer} (expected {expected})");
+                }
+                None => {
+                    missing += 1;
+                    println!("[{year}-{day:02}] {part}: missing {answer}");
+                }
+            }
+        }
+    }
+
+    println!("\n{passed} passed, {failed} failed, {missing} missing");
+    if failed > 0 {
+        return Err(Error::new(format!("{failed} answers did not match")));
+    }
+    Ok(())
+}
+
+fn main() -> ExitCode {
+    if env::args().any(|arg| arg == "--help" || arg == "-h") {
+        println!("{USAGE}");
+        return ExitCode::SUCCESS;
+    }
+
+    let args = match Args::parse(env::args().skip(1)) {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("{e}\n\n{USAGE}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    if let Some(style) = args.progress {
+        progress::set_style(style);
+    }
+    match run(&args) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("Error: {e}");
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,170 @@
+//! `--parity`: runs the Deno solution of every day solved in both languages on
+//! the same input as the Rust one and compares the answers.
+//!
+//! The Deno side is `deno/run.ts`, run as `deno run --allow-read run.ts <year>
+//! <day> [input]` from the `deno` directory. It prints one `Part <n> <answer>`
+//! line per part; anything else it prints is ignored. Its time includes Deno's
+//! start-up, so it is only comparable to the Rust time for slower days.
+
+use crate::{args::Input, bench::format_duration, load};
+use advent_of_code_2015::{input::InputSource, Error, Part};
+use std::{
+    env, fs,
+    path::{self, Path, PathBuf},
+    process::{self, Command},
+    time::{Duration, Instant},
+};
+
+/// The `deno` directory, overridable with the `AOC_DENO_DIR` environment variable.
+fn deno_dir() -> PathBuf {
+    env::var_os("AOC_DENO_DIR")
+        .map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from(concat!(env!("CARGO_MANIFEST_DIR"), "/../../deno")))
+}
+
+fn has_deno_solution(year: u16, day: u8) -> bool {
+    deno_dir()
+        .join(format!("{year}/day_{day:02}/main.ts"))
+        .is_file()
+}
+
+pub fn run(
+    year: u16,
+    days: &[u8],
+    parts: &[Part],
+    input: &Input,
+    parameters: &[(String, String)],
+) -> Result<(), Error> {
+    let (shared, rust_only): (Vec<u8>, Vec<u8>) =
+        days.iter().partition(|&&day| has_deno_solution(year, day));
+    if shared.is_empty() {
+        return Err(Error::new(format!(
+            "None of the days are solved in both Rust and Deno for {year}"
+        )));
+    }
+    if !rust_only.is_empty() {
+        println!(
+            "Skipping {} day{} without a Deno solution\n",
+            rust_only.len(),
+            if rust_only.len() == 1 { "" } else { "s" }
+        );
+    }
+
+    let (mut matched, mut mismatched, mut failed) = (0, 0, 0);
+    for day in shared {
+        let (solution, puzzle_input) = load(year, day, input, parameters)?;
+
+        let start = Instant::now();
+        let rust = solution.solve(&puzzle_input, parts);
+        let rust_time = start.elapsed();
+
+        let deno = match input {
+            Input::Source(InputSource::Default) => run_deno(year, day, None),
+            Input::Source(InputSource::Path(path)) => run_deno(year, day, Some(path)),
+            _ => {
+                // Stdin and generated inputs only exist in memory, so Deno gets
+                // a copy in a temporary file.
+                let path = env::temp_dir()
+                    .join(format!("aoc-parity-{year}-{day:02}-{}.txt", process::id()));
+                fs::write(&path, puzzle_input.text())
+                    .map_err(|e| Error::new(format!("Could not write {}: {e}", path.display())))?;
+                let deno = run_deno(year, day, Some(&path));
+                let _ = fs::remove_file(&path);
+                deno
+            }
+        };
+
+        let (rust, (deno, deno_time)) = match (rust, deno) {
+            (Ok(rust), Ok(deno)) => (rust, deno),
+            (rust, deno) => {
+                for e in [rust.err(), deno.err()].into_iter().flatten() {
+                    println!("[{year}-{day:02}] fail: {e}");
+                }
+                failed += parts.len();
+                continue;
+            }
+        };
+
+        for (part, answer) in rust {
+            match deno.iter().find(|(deno_part, _)| *deno_part == part) {
+                Some((_, deno_answer)) if *deno_answer == answer => {
+                    matched += 1;
+                    println!("[{year}-{day:02}] {part}: match {answer}");
+                }
+                Some((_, deno_answer)) => {
+                    mismatched += 1;
+                    println!(
+                        "[{year}-{day:02}] {part}: mismatch Rust {answer}, Deno {deno_answer}"
+                    );
+                }
+                None => {
+                    failed += 1;
+                    println!("[{year}-{day:02}] {part}: missing from Deno (Rust {answer})");
+                }
+            }
+        }
+        println!(
+            "[{year}-{day:02}] Rust {}, Deno {}",
+            format_duration(rust_time),
+            format_duration(deno_time)
+        );
+    }
+
+    println!("\n{matched} matched, {mismatched} mismatched, {failed} failed");
+    if mismatched + failed > 0 {
+        return Err(Error::new(format!(
+            "{} answers did not match",
+            mismatched + failed
+        )));
+    }
+    Ok(())
+}
+
+/// Runs one day's Deno solution, on `input` if given and otherwise on its
+/// default input, and returns its answers and how long it took.
+fn run_deno(
+    year: u16,
+    day: u8,
+    input: Option<&Path>,
+) -> Result<(Vec<(Part, String)>, Duration), Error> {
+    let deno = env::var_os("DENO").unwrap_or_else(|| "deno".into());
+    let mut command = Command::new(&deno);
+    command
+        .current_dir(deno_dir())
+        .args(["run", "--allow-read", "run.ts"])
+        .args([year.to_string(), day.to_string()]);
+    if let Some(input) = input {
+        // Deno runs from its own directory, so relative paths would be wrong.
+        let input = path::absolute(input)
+            .map_err(|e| Error::new(format!("Could not resolve {}: {e}", input.display())))?;
+        command.arg(input);
+    }
+
+    let start = Instant::now();
+    let output = command
+        .output()
+        .map_err(|e| Error::new(format!("Could not run {}: {e}", deno.to_string_lossy())))?;
+    let duration = start.elapsed();
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(Error::new(format!(
+            "Deno exited with {}: {}",
+            output.status,
+            stderr.trim()
+        )));
+    }
+    let answers = String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .filter_map(|line| {
+            let (part, answer) = line.strip_prefix("Part ")?.split_once(' ')?;
+            let part = match part.trim_end_matches(':') {
+                "1" => Part::One,
+                "2" => Part::Two,
+                _ => return None,
+            };
+            Some((part, answer.trim().to_string()))
+        })
+        .collect();
+    Ok((answers, duration))
+}
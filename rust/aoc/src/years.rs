@@ -0,0 +1,18 @@
+//! Every year the runner can dispatch to. A new year is a new crate in the
+//! workspace plus one arm in each of these functions.
+
+use advent_of_code_2015::DynSolution;
+
+pub fn solution(year: u16, day: u8) -> Option<&'static dyn DynSolution> {
+    match year {
+        advent_of_code_2015::YEAR => advent_of_code_2015::solution(year, day),
+        _ => None,
+    }
+}
+
+pub fn days(year: u16) -> Vec<u8> {
+    match year {
+        advent_of_code_2015::YEAR => advent_of_code_2015::days(year).collect(),
+        _ => Vec::new(),
+    }
+}